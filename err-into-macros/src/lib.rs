@@ -0,0 +1,229 @@
+//! Procedural macros for [`err-into`](https://crates.io/crates/err-into)
+//!
+//! Don't depend on this crate directly, enable the `macros` feature of `err-into` instead. This
+//! crate has no dependencies (not even `syn`), so the macros work on raw [`TokenStream`]s.
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Converts the error returned by a function using [`Into::into`]
+///
+/// The argument is the error type used inside the body. The body runs in a closure returning
+/// `Result<_, Inner>`, so `?`, `return` and the tail expression all produce the inner error,
+/// which is then converted to the error type in the signature. Rust can't infer the inner error
+/// type from `Ok(..)` and `?` alone, which is why it has to be named.
+///
+/// `async fn`s are not supported.
+#[proc_macro_attribute]
+pub fn err_into(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if attr.is_empty() {
+        return compile_error(
+            Span::call_site(),
+            "expected the error type used inside the function, e.g. `#[err_into(io::Error)]`",
+        );
+    }
+
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+    let body = match tokens.pop() {
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace => body,
+        Some(tt) => return compile_error(tt.span(), "`#[err_into]` can only be used on functions"),
+        None => return compile_error(Span::call_site(), "expected a function"),
+    };
+    if let Some(tt) = tokens.iter().find(|tt| is_ident(tt, "async")) {
+        return compile_error(tt.span(), "`#[err_into]` doesn't support `async fn`s");
+    }
+    if !tokens.iter().any(|tt| is_ident(tt, "fn")) {
+        return compile_error(body.span(), "`#[err_into]` can only be used on functions");
+    }
+
+    // ::err_into::__private::err_into_scope(move || -> ::core::result::Result<_, Inner> body)
+    let mut closure = TokenStream::new();
+    closure.extend([
+        ident("move"),
+        punct('|', Spacing::Joint),
+        punct('|', Spacing::Alone),
+    ]);
+    closure.extend([punct('-', Spacing::Joint), punct('>', Spacing::Alone)]);
+    closure.extend(path(&["core", "result", "Result"]));
+    closure.extend([
+        punct('<', Spacing::Alone),
+        ident("_"),
+        punct(',', Spacing::Alone),
+    ]);
+    closure.extend(attr);
+    closure.extend([punct('>', Spacing::Alone), TokenTree::Group(body)]);
+
+    let mut call = path(&["err_into", "__private", "err_into_scope"]);
+    call.extend([TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        closure,
+    ))]);
+
+    let mut out: TokenStream = tokens.into_iter().collect();
+    out.extend([TokenTree::Group(Group::new(Delimiter::Brace, call))]);
+    out
+}
+
+/// Generates `From<Inner>` for every single-field variant of an enum
+///
+/// Both tuple (`Io(io::Error)`) and struct (`Io { source: io::Error }`) variants are supported.
+/// Mark a variant with `#[from_variants(skip)]` to leave it out, e.g. when two variants wrap the
+/// same type. Generic enums are not supported.
+#[proc_macro_derive(FromVariants, attributes(from_variants))]
+pub fn derive_from_variants(item: TokenStream) -> TokenStream {
+    let mut tokens = item.into_iter().skip_while(|tt| !is_ident(tt, "enum"));
+    let span = match tokens.next() {
+        Some(tt) => tt.span(),
+        None => return compile_error(Span::call_site(), "`FromVariants` only supports enums"),
+    };
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(name)) => name.to_string(),
+        _ => return compile_error(span, "expected the name of the enum"),
+    };
+    let variants = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group,
+        Some(tt) => {
+            return compile_error(tt.span(), "`FromVariants` doesn't support generic enums")
+        }
+        None => return compile_error(span, "expected the variants of the enum"),
+    };
+
+    let mut out = String::new();
+    for variant in split(variants.stream(), ',') {
+        let mut skip = false;
+        let mut variant = variant.into_iter().peekable();
+        // attributes
+        while variant.peek().is_some_and(|tt| is_punct(tt, '#')) {
+            variant.next();
+            if let Some(TokenTree::Group(attr)) = variant.next() {
+                let attr = attr.stream().to_string().replace(' ', "");
+                skip |= attr == "from_variants(skip)";
+            }
+        }
+        let ident = match variant.next() {
+            Some(ident) => ident.to_string(),
+            None => continue,
+        };
+        let field = match variant.next() {
+            Some(TokenTree::Group(field)) if !skip => field,
+            _ => continue,
+        };
+        let fields = split(field.stream(), ',');
+        if fields.len() != 1 {
+            continue;
+        }
+        let (field, ty) = match field.delimiter() {
+            Delimiter::Parenthesis => (None, strip_attrs(&fields[0])),
+            Delimiter::Brace => {
+                let fields = split(strip_attrs(&fields[0]).into_iter().collect(), ':');
+                match &fields[..] {
+                    [field, ty] => (Some(field.iter().last().unwrap().to_string()), ty.clone()),
+                    _ => continue,
+                }
+            }
+            _ => continue,
+        };
+        let ty: TokenStream = ty.into_iter().collect();
+        let value = match field {
+            Some(field) => format!("{name}::{ident} {{ {field}: value }}"),
+            None => format!("{name}::{ident}(value)"),
+        };
+        out += &format!(
+            "impl ::core::convert::From<{ty}> for {name} {{ \
+                fn from(value: {ty}) -> Self {{ {value} }} \
+            }}"
+        );
+    }
+    out.parse().unwrap()
+}
+
+/// Splits `tokens` on every top level `sep`, ignoring the ones inside generics
+fn split(tokens: TokenStream, sep: char) -> Vec<Vec<TokenTree>> {
+    let mut out = vec![Vec::new()];
+    let mut depth = 0usize;
+    let mut arrow = false;
+    for tt in tokens {
+        if let TokenTree::Punct(punct) = &tt {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' if !arrow => depth = depth.saturating_sub(1),
+                ch if ch == sep && depth == 0 && !is_path_sep(punct, out.last().unwrap()) => {
+                    out.push(Vec::new());
+                    continue;
+                }
+                _ => {}
+            }
+            arrow = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+        } else {
+            arrow = false;
+        }
+        out.last_mut().unwrap().push(tt);
+    }
+    if out.last().unwrap().is_empty() {
+        out.pop();
+    }
+    out
+}
+
+/// Whether `punct` is part of a `::`
+fn is_path_sep(punct: &Punct, prev: &[TokenTree]) -> bool {
+    punct.as_char() == ':'
+        && (punct.spacing() == Spacing::Joint
+            || matches!(prev.last(), Some(TokenTree::Punct(p)) if p.as_char() == ':' && p.spacing() == Spacing::Joint))
+}
+
+/// Removes the leading `#[...]` attributes
+fn strip_attrs(tokens: &[TokenTree]) -> Vec<TokenTree> {
+    let mut tokens = tokens;
+    while let [hash, TokenTree::Group(_), rest @ ..] = tokens {
+        if !is_punct(hash, '#') {
+            break;
+        }
+        tokens = rest;
+    }
+    tokens.to_vec()
+}
+
+fn is_punct(tt: &TokenTree, ch: char) -> bool {
+    matches!(tt, TokenTree::Punct(punct) if punct.as_char() == ch)
+}
+
+fn is_ident(tt: &TokenTree, name: &str) -> bool {
+    matches!(tt, TokenTree::Ident(ident) if ident.to_string() == name)
+}
+
+fn ident(name: &str) -> TokenTree {
+    TokenTree::Ident(Ident::new(name, Span::call_site()))
+}
+
+fn punct(ch: char, spacing: Spacing) -> TokenTree {
+    TokenTree::Punct(Punct::new(ch, spacing))
+}
+
+/// An absolute path (`::a::b::c`)
+fn path(segments: &[&str]) -> TokenStream {
+    let mut out = TokenStream::new();
+    for segment in segments {
+        out.extend([
+            punct(':', Spacing::Joint),
+            punct(':', Spacing::Alone),
+            ident(segment),
+        ]);
+    }
+    out
+}
+
+/// `compile_error!("msg")` spanned at `span`
+fn compile_error(span: Span, msg: &str) -> TokenStream {
+    let mut lit = Literal::string(msg);
+    lit.set_span(span);
+    let mut args = Group::new(Delimiter::Parenthesis, TokenTree::Literal(lit).into());
+    args.set_span(span);
+
+    let mut out = TokenStream::new();
+    out.extend([
+        ident("compile_error"),
+        punct('!', Spacing::Alone),
+        TokenTree::Group(args),
+    ]);
+    out
+}
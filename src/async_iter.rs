@@ -0,0 +1,186 @@
+//! [`AsyncIterator`] adapters converting their items using [`Into::into`]
+//!
+//! Requires the `nightly` feature. The adapters are created through the [`AsyncIteratorInto`]
+//! extension trait, mirroring the ones in [`iter`](crate::iter):
+//!
+//! ```rust
+//! #![feature(async_iterator)]
+//!
+//! use std::async_iter::AsyncIterator;
+//! use std::pin::Pin;
+//! use std::sync::Arc;
+//! use std::task::{Context, Poll, Wake};
+//! use err_into::AsyncIteratorInto;
+//!
+//! struct Parse<'a>(&'a [&'a str]);
+//!
+//! impl<'a> AsyncIterator for Parse<'a> {
+//!     type Item = Result<u8, std::num::ParseIntError>;
+//!
+//!     fn poll_next(mut self: Pin<&mut Self>, _: &mut Context) -> Poll<Option<Self::Item>> {
+//!         let (first, rest) = match self.0.split_first() {
+//!             Some(split) => split,
+//!             None => return Poll::Ready(None),
+//!         };
+//!         self.0 = rest;
+//!         Poll::Ready(Some(first.parse()))
+//!     }
+//! }
+//!
+//! # struct NoopWaker;
+//! # impl Wake for NoopWaker {
+//! #     fn wake(self: Arc<Self>) {}
+//! # }
+//! # let waker = Arc::new(NoopWaker).into();
+//! # let mut cx = Context::from_waker(&waker);
+//! let mut iter = Parse(&["1", "a"]).results_into::<u32, MyError>();
+//! let mut iter = Pin::new(&mut iter);
+//! assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(Ok(1))));
+//! assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(Some(Err(MyError))));
+//! assert_eq!(iter.as_mut().poll_next(&mut cx), Poll::Ready(None));
+//!
+//! #[derive(Debug, PartialEq)]
+//! struct MyError;
+//!
+//! impl From<std::num::ParseIntError> for MyError {
+//!     fn from(_: std::num::ParseIntError) -> Self {
+//!         MyError
+//!     }
+//! }
+//! ```
+
+use core::async_iter::AsyncIterator;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use {ErrorInto, ResultInto};
+
+/// Extension trait for [`AsyncIterator`]s adding adapters that convert their items
+pub trait AsyncIteratorInto: AsyncIterator {
+    /// Converts the error of every item using [`Into::into`]
+    #[inline]
+    fn errs_into<E>(self) -> ErrsInto<Self, E>
+    where
+        Self: Sized,
+    {
+        ErrsInto {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts the value of every [`Ok`] item using [`Into::into`], leaving errors untouched
+    #[inline]
+    fn oks_into<T>(self) -> OksInto<Self, T>
+    where
+        Self: Sized,
+    {
+        OksInto {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts both the value and the error of every item using [`Into::into`]
+    #[inline]
+    fn results_into<T, E>(self) -> ResultsInto<Self, T, E>
+    where
+        Self: Sized,
+    {
+        ResultsInto {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<I> AsyncIteratorInto for I where I: AsyncIterator {}
+
+/// Async iterator returned by [`AsyncIteratorInto::errs_into`]
+#[must_use = "async iterators do nothing unless polled"]
+pub struct ErrsInto<I, E> {
+    iter: I,
+    marker: PhantomData<fn() -> E>,
+}
+
+impl_adapter!(ErrsInto < I, E > { iter });
+
+impl<I, T, E, F> AsyncIterator for ErrsInto<I, E>
+where
+    I: AsyncIterator<Item = Result<T, F>>,
+    F: Into<E>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<T, E>>> {
+        // SAFETY: `iter` is structurally pinned, it is never moved out of `self`
+        let iter = unsafe { self.map_unchecked_mut(|this| &mut this.iter) };
+        iter.poll_next(cx).err_into()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Async iterator returned by [`AsyncIteratorInto::oks_into`]
+#[must_use = "async iterators do nothing unless polled"]
+pub struct OksInto<I, T> {
+    iter: I,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl_adapter!(OksInto < I, T > { iter });
+
+impl<I, T, U, E> AsyncIterator for OksInto<I, T>
+where
+    I: AsyncIterator<Item = Result<U, E>>,
+    U: Into<T>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<T, E>>> {
+        // SAFETY: `iter` is structurally pinned, it is never moved out of `self`
+        let iter = unsafe { self.map_unchecked_mut(|this| &mut this.iter) };
+        iter.poll_next(cx).res_into()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Async iterator returned by [`AsyncIteratorInto::results_into`]
+#[must_use = "async iterators do nothing unless polled"]
+pub struct ResultsInto<I, T, E> {
+    iter: I,
+    marker: PhantomData<fn() -> (T, E)>,
+}
+
+impl_adapter!(ResultsInto < I, T, E > { iter });
+
+impl<I, T, U, E, F> AsyncIterator for ResultsInto<I, T, E>
+where
+    I: AsyncIterator<Item = Result<U, F>>,
+    U: Into<T>,
+    F: Into<E>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Result<T, E>>> {
+        // SAFETY: `iter` is structurally pinned, it is never moved out of `self`
+        let iter = unsafe { self.map_unchecked_mut(|this| &mut this.iter) };
+        iter.poll_next(cx).res_into()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,91 @@
+//! A [`Result`] wrapper whose `?` converts errors using [`Into::into`]
+
+use core::convert::Infallible;
+use core::ops::{ControlFlow, FromResidual, Try};
+
+use ResultLike;
+
+/// A [`Result`] that converts the errors propagated into it with `?` using [`Into::into`]
+///
+/// Requires the `nightly` feature. Returning it from a function or closure makes `?` accept any
+/// error converting into `E`, even when the types involved only implement [`Into`]:
+///
+/// ```rust
+/// use err_into::AutoInto;
+///
+/// struct Small(u8);
+///
+/// impl Into<i32> for Small {
+///     fn into(self) -> i32 {
+///         self.0.into()
+///     }
+/// }
+///
+/// let parse = |s: &str| -> AutoInto<i32, i32> {
+///     let val: u8 = s.parse().map_err(|_| Small(1))?;
+///     let checked = if val > 100 { Err(2i16) } else { Ok(val) };
+///     AutoInto(Ok(i32::from(checked?)))
+/// };
+///
+/// assert_eq!(parse("3").0, Ok(3));
+/// assert_eq!(parse("a").0, Err(1));
+/// assert_eq!(parse("200").0, Err(2));
+/// ```
+///
+/// Using `?` on it inside a function returning a [`Result`] works like on a [`Result`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AutoInto<T, E>(pub Result<T, E>);
+
+impl<T, E> From<Result<T, E>> for AutoInto<T, E> {
+    #[inline]
+    fn from(res: Result<T, E>) -> Self {
+        AutoInto(res)
+    }
+}
+
+impl<T, E> ResultLike for AutoInto<T, E> {
+    type Ok = T;
+    type Err = E;
+    type WithResult<U, F> = AutoInto<U, F>;
+
+    #[inline]
+    fn into_result(self) -> Result<T, E> {
+        self.0
+    }
+
+    #[inline]
+    fn from_result(res: Result<T, E>) -> Self {
+        AutoInto(res)
+    }
+}
+
+impl<T, E> Try for AutoInto<T, E> {
+    type Output = T;
+    type Residual = Result<Infallible, E>;
+
+    #[inline]
+    fn from_output(output: T) -> Self {
+        AutoInto(Ok(output))
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Result<Infallible, E>, T> {
+        match self.0 {
+            Ok(val) => ControlFlow::Continue(val),
+            Err(err) => ControlFlow::Break(Err(err)),
+        }
+    }
+}
+
+impl<T, E, F> FromResidual<Result<Infallible, F>> for AutoInto<T, E>
+where
+    F: Into<E>,
+{
+    #[inline]
+    fn from_residual(residual: Result<Infallible, F>) -> Self {
+        match residual {
+            Ok(never) => match never {},
+            Err(err) => AutoInto(Err(err.into())),
+        }
+    }
+}
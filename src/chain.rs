@@ -0,0 +1,67 @@
+//! Traversal of the [`source`](Error::source) chain of an error
+
+use core::error::Error;
+use core::iter::FusedIterator;
+
+/// An iterator over an error and its [`source`](Error::source)s
+///
+/// Built by [`Chain::new`], which starts at the given error, or by [`Sources::sources`], which
+/// starts at its source.
+#[derive(Debug, Clone)]
+pub struct Chain<'a> {
+    next: Option<&'a (dyn Error + 'static)>,
+}
+
+impl<'a> Chain<'a> {
+    /// Iterates over `err` followed by its sources
+    #[inline]
+    pub fn new(err: &'a (dyn Error + 'static)) -> Self {
+        Chain { next: Some(err) }
+    }
+}
+
+impl<'a> Iterator for Chain<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let err = self.next?;
+        self.next = err.source();
+        Some(err)
+    }
+}
+
+impl<'a> FusedIterator for Chain<'a> {}
+
+/// Iterates over the [`source`](Error::source) chain of an error
+///
+/// The error itself is not included, use [`Chain::new`] for that:
+///
+/// ```rust
+/// use std::num::ParseIntError;
+/// use err_into::{Chain, ContextInto, Msg, Sources};
+///
+/// let res: Result<u8, Msg<ParseIntError>> = "a".parse::<u8>().context_into("reading the id");
+/// let err = res.unwrap_err();
+///
+/// let sources: Vec<String> = err.sources().map(|err| err.to_string()).collect();
+/// assert_eq!(sources, ["invalid digit found in string"]);
+///
+/// let chain: Vec<String> = Chain::new(&err).map(|err| err.to_string()).collect();
+/// assert_eq!(chain, ["reading the id", "invalid digit found in string"]);
+/// ```
+pub trait Sources {
+    fn sources(&self) -> Chain<'_>;
+}
+
+impl<E> Sources for E
+where
+    E: Error + ?Sized,
+{
+    #[inline]
+    fn sources(&self) -> Chain<'_> {
+        Chain {
+            next: self.source(),
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! Element-wise conversions for the collections in `alloc` and `std`
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BinaryHeap, VecDeque};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use {Map, MapInto};
+
+/// Converts every element of a [`Vec`] using [`Into::into`]
+///
+/// Shorthand for `vec.into_iter().map(Into::into).collect()`. When `T` and `U` have the same size
+/// and alignment (like a newtype and its inner type) the standard library reuses the allocation
+/// instead of allocating a new [`Vec`]. This is an optimization of the standard library, not a
+/// guarantee.
+///
+/// ```rust
+/// use err_into::VecInto;
+///
+/// struct Meters(u32);
+///
+/// impl From<Meters> for u32 {
+///     fn from(m: Meters) -> u32 {
+///         m.0
+///     }
+/// }
+///
+/// let distances = vec![Meters(1), Meters(2)];
+/// let raw: Vec<u32> = distances.vec_into();
+/// assert_eq!(raw, [1, 2]);
+/// ```
+///
+/// [`MapInto`] is implemented for [`Vec`] too, as well as for [`VecDeque`], boxed slices and
+/// [`BinaryHeap`]:
+///
+/// ```rust
+/// use std::collections::{BinaryHeap, VecDeque};
+/// use err_into::MapInto;
+///
+/// let ring: VecDeque<u32> = VecDeque::from(vec![1u8, 2]).map_into();
+/// assert_eq!(ring, [1, 2]);
+///
+/// let boxed: Box<[u32]> = vec![1u8, 2].into_boxed_slice().map_into();
+/// assert_eq!(&*boxed, [1, 2]);
+///
+/// let heap: BinaryHeap<u32> = BinaryHeap::from(vec![1u8, 2]).map_into();
+/// assert_eq!(heap.peek(), Some(&2));
+/// ```
+///
+/// For maps, [`MapInto`] converts both keys and values, see [`KeysInto`] for the caveats of
+/// converting keys:
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use err_into::MapInto;
+///
+/// let map: BTreeMap<u8, u8> = vec![(1, 2)].into_iter().collect();
+/// let map: BTreeMap<u32, u64> = map.map_into();
+/// assert_eq!(map[&1], 2);
+/// ```
+///
+/// With the `std` feature, [`HashMap`](std::collections::HashMap) and
+/// [`HashSet`](std::collections::HashSet) are supported as well, keeping their capacity and
+/// hasher:
+///
+/// ```rust
+/// # #[cfg(feature = "std")] {
+/// use std::collections::{HashMap, HashSet};
+/// use err_into::{KeysInto, MapInto, ValuesInto};
+///
+/// let map: HashMap<u8, u8> = vec![(1, 2)].into_iter().collect();
+/// let keys: HashMap<u32, u8> = map.clone().keys_into();
+/// let values: HashMap<u8, u32> = map.clone().values_into();
+/// let both: HashMap<u32, u32> = map.map_into();
+/// assert_eq!((keys[&1], values[&1], both[&1]), (2, 2, 2));
+///
+/// let set: HashSet<u32> = vec![1u8, 2].into_iter().collect::<HashSet<_>>().map_into();
+/// assert!(set.contains(&2));
+/// # }
+/// ```
+pub trait VecInto<U> {
+    fn vec_into(self) -> Vec<U>;
+}
+
+/// Converts the keys of a map using [`Into::into`]
+///
+/// The map is rebuilt, so the converted keys are reordered according to their own [`Ord`]
+/// implementation. If several keys convert to the same value only the last entry is kept.
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use err_into::KeysInto;
+///
+/// let map: BTreeMap<u8, &str> = vec![(1, "one"), (2, "two")].into_iter().collect();
+/// let map: BTreeMap<u32, &str> = map.keys_into();
+/// assert_eq!(map[&1], "one");
+/// ```
+pub trait KeysInto<M> {
+    fn keys_into(self) -> M;
+}
+
+/// Converts the values of a map using [`Into::into`]
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use err_into::ValuesInto;
+///
+/// let map: BTreeMap<&str, u8> = vec![("one", 1), ("two", 2)].into_iter().collect();
+/// let map: BTreeMap<&str, u32> = map.values_into();
+/// assert_eq!(map["two"], 2);
+/// ```
+pub trait ValuesInto<M> {
+    fn values_into(self) -> M;
+}
+
+impl<T, U> VecInto<U> for Vec<T>
+where
+    T: Into<U>,
+{
+    #[inline]
+    fn vec_into(self) -> Vec<U> {
+        self.into_iter().map(Into::into).collect()
+    }
+}
+
+impl<T> Map for Vec<T> {
+    type Item = T;
+    type Mapped<U> = Vec<U>;
+
+    #[inline]
+    fn map_raw<U, F>(self, f: F) -> Vec<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<T> Map for VecDeque<T> {
+    type Item = T;
+    type Mapped<U> = VecDeque<U>;
+
+    #[inline]
+    fn map_raw<U, F>(self, f: F) -> VecDeque<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.into_iter().map(f).collect()
+    }
+}
+
+impl<T> Map for Box<[T]> {
+    type Item = T;
+    type Mapped<U> = Box<[U]>;
+
+    #[inline]
+    fn map_raw<U, F>(self, f: F) -> Box<[U]>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.into_vec().map_raw(f).into_boxed_slice()
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<T, U> MapInto<BinaryHeap<U>> for BinaryHeap<T>
+where
+    T: Into<U>,
+    U: Ord,
+{
+    #[inline]
+    fn map_into(self) -> BinaryHeap<U> {
+        self.into_vec().vec_into().into()
+    }
+}
+
+impl<K, L, V> KeysInto<BTreeMap<L, V>> for BTreeMap<K, V>
+where
+    K: Into<L>,
+    L: Ord,
+{
+    #[inline]
+    fn keys_into(self) -> BTreeMap<L, V> {
+        self.into_iter().map(|(k, v)| (k.into(), v)).collect()
+    }
+}
+
+impl<K, V, W> ValuesInto<BTreeMap<K, W>> for BTreeMap<K, V>
+where
+    K: Ord,
+    V: Into<W>,
+{
+    #[inline]
+    fn values_into(self) -> BTreeMap<K, W> {
+        // Keys are already sorted and unique so the map is rebuilt in linear time
+        self.into_iter().map(|(k, v)| (k, v.into())).collect()
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<K, L, V, W> MapInto<BTreeMap<L, W>> for BTreeMap<K, V>
+where
+    K: Into<L>,
+    L: Ord,
+    V: Into<W>,
+{
+    #[inline]
+    fn map_into(self) -> BTreeMap<L, W> {
+        self.into_iter()
+            .map(|(k, v)| (k.into(), v.into()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, L, V, S> KeysInto<HashMap<L, V, S>> for HashMap<K, V, S>
+where
+    K: Into<L>,
+    L: Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    #[inline]
+    fn keys_into(self) -> HashMap<L, V, S> {
+        let mut map = HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        map.extend(self.into_iter().map(|(k, v)| (k.into(), v)));
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, W, S> ValuesInto<HashMap<K, W, S>> for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: Into<W>,
+    S: BuildHasher + Clone,
+{
+    #[inline]
+    fn values_into(self) -> HashMap<K, W, S> {
+        let mut map = HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        map.extend(self.into_iter().map(|(k, v)| (k, v.into())));
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+#[diagnostic::do_not_recommend]
+impl<K, L, V, W, S> MapInto<HashMap<L, W, S>> for HashMap<K, V, S>
+where
+    K: Into<L>,
+    L: Eq + Hash,
+    V: Into<W>,
+    S: BuildHasher + Clone,
+{
+    #[inline]
+    fn map_into(self) -> HashMap<L, W, S> {
+        let mut map = HashMap::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        map.extend(self.into_iter().map(|(k, v)| (k.into(), v.into())));
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+#[diagnostic::do_not_recommend]
+impl<T, U, S> MapInto<HashSet<U, S>> for HashSet<T, S>
+where
+    T: Into<U>,
+    U: Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    #[inline]
+    fn map_into(self) -> HashSet<U, S> {
+        let mut set = HashSet::with_capacity_and_hasher(self.len(), self.hasher().clone());
+        set.extend(self.into_iter().map(Into::into));
+        set
+    }
+}
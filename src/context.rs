@@ -0,0 +1,409 @@
+//! Wrappers attaching context to an error while converting it
+
+use core::error::Error;
+use core::fmt;
+use core::panic::Location;
+#[cfg(feature = "std")]
+use std::backtrace::Backtrace;
+
+/// An error together with the location where it was converted
+///
+/// Built by [`ErrLocated::err_located`]. The [`Display`](fmt::Display) output appends the location
+/// to the message of the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Located<E> {
+    error: E,
+    location: &'static Location<'static>,
+}
+
+impl<E> Located<E> {
+    /// Wraps `error`, capturing the location of the caller
+    #[inline]
+    #[track_caller]
+    pub fn new(error: E) -> Self {
+        Located {
+            error,
+            location: Location::caller(),
+        }
+    }
+
+    /// The wrapped error
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Where the error was wrapped
+    #[inline]
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Unwraps the error, discarding the location
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E> fmt::Display for Located<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}, at {}", self.error, self.location)
+    }
+}
+
+impl<E> Error for Located<E>
+where
+    E: Error,
+{
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Converts the Error of a [`Result`] using [`Into::into`] and wraps it in a [`Located`]
+///
+/// The location is the one where `err_located` is called, so it marks where the error crossed
+/// into the converted type:
+///
+/// ```rust
+/// use err_into::{ErrLocated, Located};
+///
+/// let res: Result<(), Located<i32>> = Err(1u8).err_located();
+/// let line = line!() - 1;
+///
+/// let err = res.unwrap_err();
+/// assert_eq!(*err.error(), 1);
+/// assert_eq!(err.location().line(), line);
+/// assert_eq!(err.to_string(), format!("1, at {}", err.location()));
+/// ```
+pub trait ErrLocated<T, E> {
+    #[track_caller]
+    fn err_located(self) -> Result<T, Located<E>>;
+}
+
+impl<T, E, F> ErrLocated<T, E> for Result<T, F>
+where
+    F: Into<E>,
+{
+    #[inline]
+    #[track_caller]
+    fn err_located(self) -> Result<T, Located<E>> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(err) => Err(Located::new(err.into())),
+        }
+    }
+}
+
+/// An error together with a message describing what was being done when it happened
+///
+/// Built by [`ContextInto::context_into`] and [`ContextInto::with_context_into`]. It displays only
+/// the message and returns the error as its [`source`](Error::source), like the contexts of
+/// `anyhow`, but without allocating.
+///
+/// The message is a `&'static str` by default, but it can be any value, usually something
+/// implementing [`Display`](fmt::Display).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Msg<E, C = &'static str> {
+    msg: C,
+    error: E,
+}
+
+impl<E, C> Msg<E, C> {
+    /// Pairs `error` with `msg`
+    #[inline]
+    pub fn new(msg: C, error: E) -> Self {
+        Msg { msg, error }
+    }
+
+    /// The message attached to the error
+    #[inline]
+    pub fn msg(&self) -> &C {
+        &self.msg
+    }
+
+    /// The wrapped error
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Unwraps the error, discarding the message
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E, C> fmt::Display for Msg<E, C>
+where
+    C: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.msg.fmt(f)
+    }
+}
+
+impl<E, C> Error for Msg<E, C>
+where
+    E: Error + 'static,
+    C: fmt::Display + fmt::Debug,
+{
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Converts the Error of a [`Result`] using [`Into::into`] and attaches a message to it
+///
+/// Works without an allocator, the message is a `&'static str`:
+///
+/// ```rust
+/// use std::error::Error;
+/// use std::num::ParseIntError;
+/// use err_into::{ContextInto, Msg};
+///
+/// fn read_id(s: &str) -> Result<u8, Msg<ParseIntError>> {
+///     s.parse().context_into("reading the device id")
+/// }
+///
+/// let err = read_id("a").unwrap_err();
+/// assert_eq!(err.to_string(), "reading the device id");
+/// assert_eq!(err.source().unwrap().to_string(), "invalid digit found in string");
+/// ```
+///
+/// Or build the message lazily, only when there is an error:
+///
+/// ```rust
+/// use std::num::ParseIntError;
+/// use err_into::{ContextInto, Msg};
+///
+/// fn read_reg(s: &str, reg: u8) -> Result<u8, Msg<ParseIntError, String>> {
+///     s.parse().with_context_into(|| format!("reading register {}", reg))
+/// }
+///
+/// assert_eq!(read_reg("1", 3), Ok(1));
+/// assert_eq!(read_reg("a", 3).unwrap_err().to_string(), "reading register 3");
+/// ```
+pub trait ContextInto<T, E> {
+    fn context_into(self, msg: &'static str) -> Result<T, Msg<E>>;
+
+    /// Lazy version of [`ContextInto::context_into`], `msg` is only called on errors
+    fn with_context_into<C, G>(self, msg: G) -> Result<T, Msg<E, C>>
+    where
+        G: FnOnce() -> C;
+}
+
+impl<T, E, F> ContextInto<T, E> for Result<T, F>
+where
+    F: Into<E>,
+{
+    #[inline]
+    fn context_into(self, msg: &'static str) -> Result<T, Msg<E>> {
+        self.map_err(|err| Msg::new(msg, err.into()))
+    }
+
+    #[inline]
+    fn with_context_into<C, G>(self, msg: G) -> Result<T, Msg<E, C>>
+    where
+        G: FnOnce() -> C,
+    {
+        self.map_err(|err| Msg::new(msg(), err.into()))
+    }
+}
+
+/// An error together with a numeric error code
+///
+/// Built by [`WithCodeInto::with_code_into`], for reporting errors as codes (telemetry, exit
+/// statuses or FFI) while keeping the original error around. The code is a `u32` by default.
+///
+/// A blanket `From<Code<E>> for E` is not allowed by the coherence rules, so use
+/// [`Code::into_error`] to get back the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Code<E, N = u32> {
+    code: N,
+    error: E,
+}
+
+impl<E, N> Code<E, N> {
+    /// Pairs `error` with `code`
+    #[inline]
+    pub fn new(code: N, error: E) -> Self {
+        Code { code, error }
+    }
+
+    /// The code attached to the error
+    #[inline]
+    pub fn code(&self) -> &N {
+        &self.code
+    }
+
+    /// The wrapped error
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Unwraps the error, discarding the code
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E, N> fmt::Display for Code<E, N>
+where
+    E: fmt::Display,
+    N: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (code {})", self.error, self.code)
+    }
+}
+
+impl<E, N> Error for Code<E, N>
+where
+    E: Error,
+    N: fmt::Display + fmt::Debug,
+{
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+}
+
+/// Converts the Error of a [`Result`] using [`Into::into`] and attaches a numeric code to it
+///
+/// ```rust
+/// use err_into::{Code, WithCodeInto};
+///
+/// let res: Result<(), Code<i32, u16>> = Err(1u8).with_code_into(404);
+///
+/// let err = res.unwrap_err();
+/// assert_eq!(*err.code(), 404);
+/// assert_eq!(err.to_string(), "1 (code 404)");
+/// assert_eq!(err.into_error(), 1);
+/// ```
+pub trait WithCodeInto<T, E> {
+    fn with_code_into<N>(self, code: N) -> Result<T, Code<E, N>>;
+}
+
+impl<T, E, F> WithCodeInto<T, E> for Result<T, F>
+where
+    F: Into<E>,
+{
+    #[inline]
+    fn with_code_into<N>(self, code: N) -> Result<T, Code<E, N>> {
+        self.map_err(|err| Code::new(code, err.into()))
+    }
+}
+
+/// An error together with the backtrace captured when it was converted
+///
+/// Built by [`ErrTraced::err_traced`]. The backtrace is captured with [`Backtrace::capture`], so
+/// it is only collected when enabled through the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE`
+/// environment variables.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Traced<E> {
+    error: E,
+    backtrace: Backtrace,
+}
+
+#[cfg(feature = "std")]
+impl<E> Traced<E> {
+    /// Wraps `error`, capturing a backtrace
+    #[inline]
+    pub fn new(error: E) -> Self {
+        Traced {
+            error,
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// The wrapped error
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// The backtrace captured when the error was wrapped
+    #[inline]
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    /// Unwraps the error, discarding the backtrace
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> fmt::Display for Traced<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> Error for Traced<E>
+where
+    E: Error,
+{
+    #[inline]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.error.source()
+    }
+
+    #[cfg(feature = "nightly")]
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        request.provide_ref(&self.backtrace);
+        self.error.provide(request);
+    }
+}
+
+/// Converts the Error of a [`Result`] using [`Into::into`] and wraps it in a [`Traced`]
+///
+/// Keeps the concrete error type while still recording where it happened:
+///
+/// ```rust
+/// use std::backtrace::BacktraceStatus;
+/// use err_into::{ErrTraced, Traced};
+///
+/// let res: Result<(), Traced<i32>> = Err(1u8).err_traced();
+///
+/// let err = res.unwrap_err();
+/// assert_eq!(*err.error(), 1);
+///
+/// // Only captured when enabled
+/// let enabled = ["RUST_BACKTRACE", "RUST_LIB_BACKTRACE"].iter().any(|var| std::env::var_os(var).is_some());
+/// if !enabled {
+///     assert_eq!(err.backtrace().status(), BacktraceStatus::Disabled);
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub trait ErrTraced<T, E> {
+    fn err_traced(self) -> Result<T, Traced<E>>;
+}
+
+#[cfg(feature = "std")]
+impl<T, E, F> ErrTraced<T, E> for Result<T, F>
+where
+    F: Into<E>,
+{
+    #[inline]
+    fn err_traced(self) -> Result<T, Traced<E>> {
+        self.map_err(|err| Traced::new(err.into()))
+    }
+}
@@ -0,0 +1,79 @@
+//! Free function versions of the extension traits
+//!
+//! These are useful when a function is expected instead of a method, like in [`Iterator::map`] or
+//! [`Option::map`]:
+//!
+//! ```rust
+//! use err_into::fns;
+//!
+//! let results = vec![Ok(1u8), Err(2u8)];
+//! let converted: Vec<Result<u8, i32>> = results.into_iter().map(fns::err_into).collect();
+//! assert_eq!(converted, [Ok(1), Err(2)]);
+//! ```
+
+use {AllInto, ErrorInto, MapInto, ResultInto};
+
+/// Function version of [`ErrorInto::err_into`]
+///
+/// ```rust
+/// use err_into::fns::err_into;
+///
+/// let res: Option<Result<(), i32>> = Some(Err(0u8)).map(err_into);
+/// assert_eq!(res, Some(Err(0)));
+/// ```
+#[inline]
+pub fn err_into<T, E, F>(res: Result<T, F>) -> Result<T, E>
+where
+    F: Into<E>,
+{
+    res.err_into()
+}
+
+/// Function version of [`ResultInto::res_into`]
+///
+/// ```rust
+/// use err_into::fns::res_into;
+///
+/// let res: Option<Result<i32, i16>> = Some(Ok::<u8, i8>(0)).map(res_into);
+/// assert_eq!(res, Some(Ok(0)));
+/// ```
+#[inline]
+pub fn res_into<T, U, E, F>(res: Result<U, F>) -> Result<T, E>
+where
+    F: Into<E>,
+    U: Into<T>,
+{
+    res.res_into()
+}
+
+/// Function version of [`MapInto::map_into`]
+///
+/// ```rust
+/// use err_into::fns::map_into;
+///
+/// let values: Vec<Option<i32>> = vec![Some(0u8), None].into_iter().map(map_into).collect();
+/// assert_eq!(values, [Some(0), None]);
+/// ```
+#[inline]
+pub fn map_into<T, U>(val: T) -> U
+where
+    T: MapInto<U>,
+{
+    val.map_into()
+}
+
+/// Function version of [`AllInto::all_into`]
+///
+/// ```rust
+/// use err_into::fns::all_into;
+///
+/// let all: Result<(u8, u16), i32> = all_into((Ok::<u8, u8>(1), Ok::<u16, i16>(2)));
+/// assert_eq!(all, Ok((1, 2)));
+/// ```
+#[inline]
+pub fn all_into<R, T, E>(results: R) -> Result<T, E>
+where
+    R: AllInto<T, E>,
+{
+    results.all_into()
+}
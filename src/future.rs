@@ -0,0 +1,193 @@
+//! [`Future`] adapters converting their output using [`Into::into`]
+//!
+//! The adapters are created through the [`FutureInto`] extension trait and don't allocate or
+//! depend on any executor:
+//!
+//! ```rust
+//! use std::future::Future;
+//! use err_into::FutureInto;
+//!
+//! fn fetch() -> impl Future<Output = Result<(), u8>> {
+//!     std::future::ready(Err(1))
+//! }
+//!
+//! fn fetch_wide() -> impl Future<Output = Result<(), i32>> {
+//!     fetch().err_into()
+//! }
+//! ```
+
+use core::future::Future;
+use core::marker::PhantomData;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use {ErrorInto, ResultInto};
+
+/// Extension trait for [`Future`]s adding adapters that convert their output
+pub trait FutureInto: Future {
+    /// Converts the error of the output using [`Into::into`]
+    ///
+    /// The [`Future`] version of [`ErrorInto::err_into`]:
+    ///
+    /// ```rust
+    /// use std::future::{ready, Future};
+    /// use std::pin::Pin;
+    /// use std::sync::Arc;
+    /// use std::task::{Context, Poll, Wake};
+    /// use err_into::FutureInto;
+    ///
+    /// # struct NoopWaker;
+    /// # impl Wake for NoopWaker {
+    /// #     fn wake(self: Arc<Self>) {}
+    /// # }
+    /// # let waker = Arc::new(NoopWaker).into();
+    /// # let mut cx = Context::from_waker(&waker);
+    /// let mut fut = ready(Err::<(), u8>(1)).err_into::<i32>();
+    /// assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Ready(Err(1)));
+    /// ```
+    #[inline]
+    fn err_into<E>(self) -> ErrInto<Self, E>
+    where
+        Self: Sized,
+    {
+        ErrInto {
+            future: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts the output using [`Into::into`]
+    ///
+    /// ```rust
+    /// use std::future::{ready, Future};
+    /// use std::pin::Pin;
+    /// use std::sync::Arc;
+    /// use std::task::{Context, Poll, Wake};
+    /// use err_into::FutureInto;
+    ///
+    /// # struct NoopWaker;
+    /// # impl Wake for NoopWaker {
+    /// #     fn wake(self: Arc<Self>) {}
+    /// # }
+    /// # let waker = Arc::new(NoopWaker).into();
+    /// # let mut cx = Context::from_waker(&waker);
+    /// let mut fut = ready(1u8).map_output_into::<u32>();
+    /// assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Ready(1));
+    /// ```
+    #[inline]
+    fn map_output_into<U>(self) -> MapOutputInto<Self, U>
+    where
+        Self: Sized,
+    {
+        MapOutputInto {
+            future: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts both the value and the error of the output using [`Into::into`]
+    ///
+    /// The [`Future`] version of [`ResultInto::res_into`]:
+    ///
+    /// ```rust
+    /// use std::future::{ready, Future};
+    /// use std::pin::Pin;
+    /// use std::sync::Arc;
+    /// use std::task::{Context, Poll, Wake};
+    /// use err_into::FutureInto;
+    ///
+    /// # struct NoopWaker;
+    /// # impl Wake for NoopWaker {
+    /// #     fn wake(self: Arc<Self>) {}
+    /// # }
+    /// # let waker = Arc::new(NoopWaker).into();
+    /// # let mut cx = Context::from_waker(&waker);
+    /// let mut fut = ready(Ok::<u8, i8>(1)).res_into::<u32, i32>();
+    /// assert_eq!(Pin::new(&mut fut).poll(&mut cx), Poll::Ready(Ok(1)));
+    /// ```
+    #[inline]
+    fn res_into<T, E>(self) -> ResInto<Self, T, E>
+    where
+        Self: Sized,
+    {
+        ResInto {
+            future: self,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F> FutureInto for F where F: Future {}
+
+/// Future returned by [`FutureInto::err_into`]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ErrInto<Fut, E> {
+    future: Fut,
+    marker: PhantomData<fn() -> E>,
+}
+
+impl_adapter!(ErrInto < Fut, E > { future });
+
+impl<Fut, T, E, F> Future for ErrInto<Fut, E>
+where
+    Fut: Future<Output = Result<T, F>>,
+    F: Into<E>,
+{
+    type Output = Result<T, E>;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<T, E>> {
+        // SAFETY: `future` is structurally pinned, it is never moved out of `self`
+        let future = unsafe { self.map_unchecked_mut(|this| &mut this.future) };
+        future.poll(cx).err_into()
+    }
+}
+
+/// Future returned by [`FutureInto::map_output_into`]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MapOutputInto<Fut, U> {
+    future: Fut,
+    marker: PhantomData<fn() -> U>,
+}
+
+impl_adapter!(MapOutputInto < Fut, U > { future });
+
+impl<Fut, U> Future for MapOutputInto<Fut, U>
+where
+    Fut: Future,
+    Fut::Output: Into<U>,
+{
+    type Output = U;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<U> {
+        // SAFETY: `future` is structurally pinned, it is never moved out of `self`
+        let future = unsafe { self.map_unchecked_mut(|this| &mut this.future) };
+        future.poll(cx).map(Into::into)
+    }
+}
+
+/// Future returned by [`FutureInto::res_into`]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct ResInto<Fut, T, E> {
+    future: Fut,
+    marker: PhantomData<fn() -> (T, E)>,
+}
+
+impl_adapter!(ResInto < Fut, T, E > { future });
+
+impl<Fut, T, U, E, F> Future for ResInto<Fut, T, E>
+where
+    Fut: Future<Output = Result<U, F>>,
+    U: Into<T>,
+    F: Into<E>,
+{
+    type Output = Result<T, E>;
+
+    #[inline]
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<T, E>> {
+        // SAFETY: `future` is structurally pinned, it is never moved out of `self`
+        let future = unsafe { self.map_unchecked_mut(|this| &mut this.future) };
+        future.poll(cx).res_into()
+    }
+}
@@ -0,0 +1,195 @@
+//! Combinators moving errors into allocated types
+
+use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use core::error::Error;
+use core::fmt;
+
+/// Boxes the Error of a [`Result`] into a `Box<dyn Error + Send + Sync>`
+///
+/// The most common "catch all" error type when not using a crate like `anyhow`:
+///
+/// ```rust
+/// use std::error::Error;
+/// use err_into::ErrBoxed;
+///
+/// fn parse(s: &str) -> Result<u8, Box<dyn Error + Send + Sync>> {
+///     s.parse::<u8>().err_boxed()
+/// }
+///
+/// assert_eq!(parse("1").unwrap(), 1);
+/// assert_eq!(parse("a").unwrap_err().to_string(), "invalid digit found in string");
+/// ```
+pub trait ErrBoxed<T> {
+    fn err_boxed(self) -> Result<T, Box<dyn Error + Send + Sync>>;
+}
+
+impl<T, E> ErrBoxed<T> for Result<T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    #[inline]
+    fn err_boxed(self) -> Result<T, Box<dyn Error + Send + Sync>> {
+        self.map_err(|err| Box::new(err) as Box<dyn Error + Send + Sync>)
+    }
+}
+
+/// Wraps the Error of a [`Result`] in an [`Arc`] so it can be shared between threads
+///
+/// Shorthand for `result.map_err(Arc::new)`
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use err_into::ErrArc;
+///
+/// let res: Result<(), Arc<u8>> = Err(1).err_arc();
+/// let shared = res.unwrap_err();
+/// let consumers = (Arc::clone(&shared), Arc::clone(&shared));
+/// assert_eq!(*consumers.0, 1);
+/// ```
+#[cfg(target_has_atomic = "ptr")]
+pub trait ErrArc<T, E> {
+    fn err_arc(self) -> Result<T, Arc<E>>;
+}
+
+/// Wraps the Error of a [`Result`] in an [`Rc`] so it can be shared
+///
+/// Shorthand for `result.map_err(Rc::new)`
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use err_into::ErrRc;
+///
+/// let res: Result<(), Rc<u8>> = Err(1).err_rc();
+/// assert_eq!(*res.unwrap_err(), 1);
+/// ```
+pub trait ErrRc<T, E> {
+    fn err_rc(self) -> Result<T, Rc<E>>;
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T, E> ErrArc<T, E> for Result<T, E> {
+    #[inline]
+    fn err_arc(self) -> Result<T, Arc<E>> {
+        self.map_err(Arc::new)
+    }
+}
+
+impl<T, E> ErrRc<T, E> for Result<T, E> {
+    #[inline]
+    fn err_rc(self) -> Result<T, Rc<E>> {
+        self.map_err(Rc::new)
+    }
+}
+
+/// Converts the Error of a [`Result`] into a [`String`] using its [`Display`](fmt::Display)
+/// implementation
+///
+/// Shorthand for `result.map_err(|err| err.to_string())`
+///
+/// ```rust
+/// use err_into::ErrToString;
+///
+/// fn parse(s: &str) -> Result<u8, String> {
+///     s.parse::<u8>().err_to_string()
+/// }
+///
+/// assert_eq!(parse("a"), Err("invalid digit found in string".to_string()));
+/// ```
+pub trait ErrToString<T> {
+    fn err_to_string(self) -> Result<T, String>;
+}
+
+/// Converts the Error of a [`Result`] into a [`String`] using its [`Debug`](fmt::Debug)
+/// implementation
+///
+/// Shorthand for `result.map_err(|err| format!("{:?}", err))`. Meant for prototypes and tests,
+/// prefer [`ErrToString`] for messages shown to users.
+///
+/// ```rust
+/// use err_into::ErrDebug;
+///
+/// #[derive(Debug)]
+/// struct NoDisplay(u8);
+///
+/// let res: Result<(), NoDisplay> = Err(NoDisplay(1));
+/// assert_eq!(res.err_debug(), Err("NoDisplay(1)".to_string()));
+/// ```
+pub trait ErrDebug<T> {
+    fn err_debug(self) -> Result<T, String>;
+}
+
+/// Converts a borrowed Error of a [`Result`] into its owned counterpart
+///
+/// Works with [`Cow`]s and references to types implementing [`ToOwned`], so the [`Result`] can
+/// outlive the borrow:
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use err_into::ErrIntoOwned;
+///
+/// fn check(input: &str) -> Result<(), String> {
+///     let res: Result<(), Cow<str>> = Err(Cow::Borrowed(input));
+///     res.err_into_owned()
+/// }
+///
+/// assert_eq!(check("bad input"), Err("bad input".to_string()));
+///
+/// let res: Result<(), &str> = Err("borrowed");
+/// assert_eq!(res.err_into_owned(), Err("borrowed".to_string()));
+/// ```
+pub trait ErrIntoOwned<T> {
+    /// The owned Error type
+    type Owned;
+
+    fn err_into_owned(self) -> Result<T, Self::Owned>;
+}
+
+impl<T, E> ErrToString<T> for Result<T, E>
+where
+    E: fmt::Display,
+{
+    #[inline]
+    fn err_to_string(self) -> Result<T, String> {
+        self.map_err(|err| err.to_string())
+    }
+}
+
+impl<T, E> ErrDebug<T> for Result<T, E>
+where
+    E: fmt::Debug,
+{
+    #[inline]
+    fn err_debug(self) -> Result<T, String> {
+        self.map_err(|err| format!("{:?}", err))
+    }
+}
+
+impl<'a, T, B> ErrIntoOwned<T> for Result<T, Cow<'a, B>>
+where
+    B: ToOwned + ?Sized,
+{
+    type Owned = B::Owned;
+
+    #[inline]
+    fn err_into_owned(self) -> Result<T, B::Owned> {
+        self.map_err(Cow::into_owned)
+    }
+}
+
+impl<T, B> ErrIntoOwned<T> for Result<T, &B>
+where
+    B: ToOwned + ?Sized,
+{
+    type Owned = B::Owned;
+
+    #[inline]
+    fn err_into_owned(self) -> Result<T, B::Owned> {
+        self.map_err(ToOwned::to_owned)
+    }
+}
@@ -0,0 +1,34 @@
+//! Conversions into [`std::io::Error`]
+
+use core::error::Error;
+use std::io;
+
+/// Converts the Error of a [`Result`] into an [`io::Error`] of the given [`io::ErrorKind`]
+///
+/// Shorthand for `result.map_err(|err| io::Error::new(kind, err))`, which comes up constantly when
+/// implementing [`Read`](std::io::Read) or [`Write`](std::io::Write) wrappers:
+///
+/// ```rust
+/// use std::io::{self, ErrorKind};
+/// use err_into::ErrIntoIo;
+///
+/// fn read_len(header: &str) -> io::Result<usize> {
+///     header.trim().parse::<usize>().err_into_io(ErrorKind::InvalidData)
+/// }
+///
+/// assert_eq!(read_len("12\n").unwrap(), 12);
+/// assert_eq!(read_len("twelve").unwrap_err().kind(), ErrorKind::InvalidData);
+/// ```
+pub trait ErrIntoIo<T> {
+    fn err_into_io(self, kind: io::ErrorKind) -> io::Result<T>;
+}
+
+impl<T, E> ErrIntoIo<T> for Result<T, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    #[inline]
+    fn err_into_io(self, kind: io::ErrorKind) -> io::Result<T> {
+        self.map_err(|err| io::Error::new(kind, err))
+    }
+}
@@ -0,0 +1,592 @@
+//! Iterator adapters converting their items using [`Into::into`]
+//!
+//! The adapters are created through the [`IteratorInto`] extension trait:
+//!
+//! ```rust
+//! use err_into::IteratorInto;
+//!
+//! let results = vec![Ok(1u8), Err(2u8)];
+//! let converted: Vec<Result<u8, i32>> = results.into_iter().errs_into().collect();
+//! assert_eq!(converted, [Ok(1), Err(2)]);
+//! ```
+
+use core::iter::{FromIterator, FusedIterator};
+use core::marker::PhantomData;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use {ErrorInto, MapInto, ResultInto};
+
+/// Extension trait for [`Iterator`]s adding adapters that convert their items
+pub trait IteratorInto: Iterator {
+    /// Converts the error of every item using [`Into::into`]
+    ///
+    /// The iterator version of [`ErrorInto::err_into`]:
+    ///
+    /// ```rust
+    /// use err_into::IteratorInto;
+    ///
+    /// let mut iter = ["1", "a"].iter().map(|s| s.parse::<u8>()).errs_into::<MyError>();
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Err(MyError)));
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct MyError;
+    ///
+    /// impl From<std::num::ParseIntError> for MyError {
+    ///     fn from(_: std::num::ParseIntError) -> Self {
+    ///         MyError
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn errs_into<E>(self) -> ErrsInto<Self, E>
+    where
+        Self: Sized,
+    {
+        ErrsInto {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts the value of every [`Ok`] item using [`Into::into`], leaving errors untouched
+    ///
+    /// The iterator version of [`MapInto::map_into`] for [`Result`]s:
+    ///
+    /// ```rust
+    /// use err_into::IteratorInto;
+    ///
+    /// let parsed = ["1", "a"].iter().map(|s| s.parse::<u8>());
+    /// let widened: Vec<Result<u64, _>> = parsed.oks_into().collect();
+    /// assert_eq!(widened[0], Ok(1));
+    /// assert!(widened[1].is_err());
+    /// ```
+    #[inline]
+    fn oks_into<T>(self) -> OksInto<Self, T>
+    where
+        Self: Sized,
+    {
+        OksInto {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts both the value and the error of every item using [`Into::into`]
+    ///
+    /// The iterator version of [`ResultInto::res_into`]:
+    ///
+    /// ```rust
+    /// use err_into::IteratorInto;
+    ///
+    /// let results = vec![Ok(1u8), Err(2i8)];
+    /// let converted: Vec<Result<u32, i32>> = results.into_iter().results_into().collect();
+    /// assert_eq!(converted, [Ok(1), Err(2)]);
+    /// ```
+    #[inline]
+    fn results_into<T, E>(self) -> ResultsInto<Self, T, E>
+    where
+        Self: Sized,
+    {
+        ResultsInto {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts every item using [`Into::into`]
+    ///
+    /// Shorthand for `iter.map(Into::into)` that doesn't need the target type in a closure:
+    ///
+    /// ```rust
+    /// use err_into::IteratorInto;
+    ///
+    /// let bytes = [1u8, 2, 3];
+    /// let sum: u32 = bytes.iter().copied().map_into_items::<u32>().sum();
+    /// assert_eq!(sum, 6);
+    /// ```
+    #[inline]
+    fn map_into_items<U>(self) -> MapIntoItems<Self, U>
+    where
+        Self: Sized,
+    {
+        MapIntoItems {
+            iter: self,
+            marker: PhantomData,
+        }
+    }
+
+    /// Collects an iterator of [`Result`]s converting both the values and the error using
+    /// [`Into::into`]
+    ///
+    /// Like collecting into a [`Result`], it stops at the first error:
+    ///
+    /// ```rust
+    /// use err_into::IteratorInto;
+    ///
+    /// let ok: Vec<Result<u8, i8>> = vec![Ok(1), Ok(2)];
+    /// let collected: Result<Vec<u32>, i32> = ok.into_iter().collect_results_into();
+    /// assert_eq!(collected, Ok(vec![1, 2]));
+    ///
+    /// let err = vec![Ok(1u8), Err(-2i8), Err(-3i8)];
+    /// let collected: Result<Vec<u32>, i32> = err.into_iter().collect_results_into();
+    /// assert_eq!(collected, Err(-2));
+    /// ```
+    #[inline]
+    fn collect_results_into<C, T, E>(self) -> Result<C, E>
+    where
+        Self: Sized,
+        ResultsInto<Self, T, E>: Iterator<Item = Result<T, E>>,
+        C: FromIterator<T>,
+    {
+        self.results_into().collect()
+    }
+
+    /// Collects an iterator of [`Result`]s converting both the values and the errors using
+    /// [`Into::into`], gathering every error instead of stopping at the first one
+    ///
+    /// ```rust
+    /// use err_into::IteratorInto;
+    ///
+    /// let results = vec![Ok(1u8), Err(-2i8), Ok(3), Err(-4)];
+    /// let collected: Result<Vec<u32>, Vec<i32>> = results.into_iter().collect_all_errs_into();
+    /// assert_eq!(collected, Err(vec![-2, -4]));
+    ///
+    /// let results: Vec<Result<u8, i8>> = vec![Ok(1), Ok(3)];
+    /// let collected: Result<Vec<u32>, Vec<i32>> = results.into_iter().collect_all_errs_into();
+    /// assert_eq!(collected, Ok(vec![1, 3]));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    #[inline]
+    fn collect_all_errs_into<C, T, E>(self) -> Result<C, Vec<E>>
+    where
+        Self: Sized,
+        ResultsInto<Self, T, E>: Iterator<Item = Result<T, E>>,
+        C: FromIterator<T>,
+    {
+        let mut errs = Vec::new();
+        let collected = self
+            .results_into()
+            .filter_map(|res| match res {
+                Ok(val) => Some(val),
+                Err(err) => {
+                    errs.push(err);
+                    None
+                }
+            })
+            .collect();
+        if errs.is_empty() {
+            Ok(collected)
+        } else {
+            Err(errs)
+        }
+    }
+
+    /// Partitions an iterator of [`Result`]s into the converted values and the converted errors
+    ///
+    /// Any collections implementing [`Default`] and [`Extend`] can be used, so it works without
+    /// allocating too. The item types are taken from their [`IntoIterator`] implementations:
+    ///
+    /// ```rust
+    /// use err_into::IteratorInto;
+    ///
+    /// let results = vec![Ok(1u8), Err(-2i8), Ok(3), Err(-4)];
+    /// let (oks, errs): (Vec<u32>, Vec<i32>) = results.into_iter().partition_into();
+    /// assert_eq!(oks, [1, 3]);
+    /// assert_eq!(errs, [-2, -4]);
+    /// ```
+    #[inline]
+    fn partition_into<A, B>(self) -> (A, B)
+    where
+        Self: Sized,
+        ResultsInto<Self, A::Item, B::Item>: Iterator<Item = Result<A::Item, B::Item>>,
+        A: Default + Extend<<A as IntoIterator>::Item> + IntoIterator,
+        B: Default + Extend<<B as IntoIterator>::Item> + IntoIterator,
+    {
+        let mut oks = A::default();
+        let mut errs = B::default();
+        for res in self.results_into() {
+            match res {
+                Ok(val) => oks.extend(Some(val)),
+                Err(err) => errs.extend(Some(err)),
+            }
+        }
+        (oks, errs)
+    }
+
+    /// [`Iterator::try_fold`] converting the error returned by `f` using [`Into::into`]
+    ///
+    /// ```rust
+    /// use err_into::IteratorInto;
+    ///
+    /// fn parse_sum(values: &[&str]) -> Result<u32, MyError> {
+    ///     values.iter().try_fold_into(0u32, |acc, s| s.parse::<u32>().map(|x| acc + x))
+    /// }
+    ///
+    /// assert_eq!(parse_sum(&["1", "2"]), Ok(3));
+    /// assert_eq!(parse_sum(&["1", "a"]), Err(MyError));
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct MyError;
+    ///
+    /// impl From<std::num::ParseIntError> for MyError {
+    ///     fn from(_: std::num::ParseIntError) -> Self {
+    ///         MyError
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn try_fold_into<B, G, F, E>(&mut self, init: B, mut f: G) -> Result<B, E>
+    where
+        Self: Sized,
+        G: FnMut(B, Self::Item) -> Result<B, F>,
+        F: Into<E>,
+    {
+        self.try_fold(init, |acc, item| f(acc, item).err_into())
+    }
+
+    /// [`Iterator::try_for_each`] converting the error returned by `f` using [`Into::into`]
+    ///
+    /// ```rust
+    /// use err_into::IteratorInto;
+    ///
+    /// fn check(values: &[u8]) -> Result<(), i32> {
+    ///     values.iter().try_for_each_into(|&x| if x < 3 { Ok(()) } else { Err(x) })
+    /// }
+    ///
+    /// assert_eq!(check(&[1, 2]), Ok(()));
+    /// assert_eq!(check(&[1, 3, 4]), Err(3));
+    /// ```
+    #[inline]
+    fn try_for_each_into<G, F, E>(&mut self, mut f: G) -> Result<(), E>
+    where
+        Self: Sized,
+        G: FnMut(Self::Item) -> Result<(), F>,
+        F: Into<E>,
+    {
+        self.try_for_each(|item| f(item).err_into())
+    }
+
+    /// Returns the first [`Ok`] value or, if every item is an error, the last error, converting
+    /// them using [`Into::into`]
+    ///
+    /// Returns [`None`] if the iterator is empty. Only the returned error is converted:
+    ///
+    /// ```rust
+    /// use err_into::IteratorInto;
+    ///
+    /// let probes = vec![Err(1u8), Ok(2u8), Err(3)];
+    /// assert_eq!(probes.into_iter().first_ok_into(), Some(Ok::<u32, i32>(2)));
+    ///
+    /// let probes: Vec<Result<u8, u8>> = vec![Err(1), Err(3)];
+    /// assert_eq!(probes.into_iter().first_ok_into(), Some(Err::<u32, i32>(3)));
+    ///
+    /// let probes: Vec<Result<u8, u8>> = vec![];
+    /// assert_eq!(probes.into_iter().first_ok_into(), None::<Result<u32, i32>>);
+    /// ```
+    #[inline]
+    fn first_ok_into<T, E, U, F>(self) -> Option<Result<T, E>>
+    where
+        Self: Sized + Iterator<Item = Result<U, F>>,
+        U: Into<T>,
+        F: Into<E>,
+    {
+        let mut last = None;
+        for res in self {
+            match res {
+                Ok(val) => return Some(Ok(val.into())),
+                Err(err) => last = Some(err),
+            }
+        }
+        last.map(|err| Err(err.into()))
+    }
+}
+
+impl<I> IteratorInto for I where I: Iterator {}
+
+/// Iterator returned by [`IteratorInto::errs_into`]
+pub struct ErrsInto<I, E> {
+    iter: I,
+    marker: PhantomData<fn() -> E>,
+}
+
+impl_adapter!(ErrsInto < I, E > { iter });
+
+impl<I, T, E, F> Iterator for ErrsInto<I, E>
+where
+    I: Iterator<Item = Result<T, F>>,
+    F: Into<E>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T, E>> {
+        self.iter.next().map(ErrorInto::err_into)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, E, F> DoubleEndedIterator for ErrsInto<I, E>
+where
+    I: DoubleEndedIterator<Item = Result<T, F>>,
+    F: Into<E>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Result<T, E>> {
+        self.iter.next_back().map(ErrorInto::err_into)
+    }
+}
+
+impl<I, T, E, F> ExactSizeIterator for ErrsInto<I, E>
+where
+    I: ExactSizeIterator<Item = Result<T, F>>,
+    F: Into<E>,
+{
+}
+
+impl<I, T, E, F> FusedIterator for ErrsInto<I, E>
+where
+    I: FusedIterator<Item = Result<T, F>>,
+    F: Into<E>,
+{
+}
+
+/// Iterator returned by [`IteratorInto::oks_into`]
+pub struct OksInto<I, T> {
+    iter: I,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl_adapter!(OksInto < I, T > { iter });
+
+impl<I, T, U, E> Iterator for OksInto<I, T>
+where
+    I: Iterator<Item = Result<U, E>>,
+    U: Into<T>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T, E>> {
+        self.iter.next().map(MapInto::map_into)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, U, E> DoubleEndedIterator for OksInto<I, T>
+where
+    I: DoubleEndedIterator<Item = Result<U, E>>,
+    U: Into<T>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Result<T, E>> {
+        self.iter.next_back().map(MapInto::map_into)
+    }
+}
+
+impl<I, T, U, E> ExactSizeIterator for OksInto<I, T>
+where
+    I: ExactSizeIterator<Item = Result<U, E>>,
+    U: Into<T>,
+{
+}
+
+impl<I, T, U, E> FusedIterator for OksInto<I, T>
+where
+    I: FusedIterator<Item = Result<U, E>>,
+    U: Into<T>,
+{
+}
+
+/// Iterator returned by [`IteratorInto::results_into`]
+pub struct ResultsInto<I, T, E> {
+    iter: I,
+    marker: PhantomData<fn() -> (T, E)>,
+}
+
+impl_adapter!(ResultsInto < I, T, E > { iter });
+
+impl<I, T, U, E, F> Iterator for ResultsInto<I, T, E>
+where
+    I: Iterator<Item = Result<U, F>>,
+    U: Into<T>,
+    F: Into<E>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T, E>> {
+        self.iter.next().map(ResultInto::res_into)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, T, U, E, F> DoubleEndedIterator for ResultsInto<I, T, E>
+where
+    I: DoubleEndedIterator<Item = Result<U, F>>,
+    U: Into<T>,
+    F: Into<E>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Result<T, E>> {
+        self.iter.next_back().map(ResultInto::res_into)
+    }
+}
+
+impl<I, T, U, E, F> ExactSizeIterator for ResultsInto<I, T, E>
+where
+    I: ExactSizeIterator<Item = Result<U, F>>,
+    U: Into<T>,
+    F: Into<E>,
+{
+}
+
+impl<I, T, U, E, F> FusedIterator for ResultsInto<I, T, E>
+where
+    I: FusedIterator<Item = Result<U, F>>,
+    U: Into<T>,
+    F: Into<E>,
+{
+}
+
+/// Iterator returned by [`IteratorInto::map_into_items`]
+pub struct MapIntoItems<I, U> {
+    iter: I,
+    marker: PhantomData<fn() -> U>,
+}
+
+impl_adapter!(MapIntoItems < I, U > { iter });
+
+impl<I, U> Iterator for MapIntoItems<I, U>
+where
+    I: Iterator,
+    I::Item: Into<U>,
+{
+    type Item = U;
+
+    #[inline]
+    fn next(&mut self) -> Option<U> {
+        self.iter.next().map(Into::into)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, U> DoubleEndedIterator for MapIntoItems<I, U>
+where
+    I: DoubleEndedIterator,
+    I::Item: Into<U>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<U> {
+        self.iter.next_back().map(Into::into)
+    }
+}
+
+impl<I, U> ExactSizeIterator for MapIntoItems<I, U>
+where
+    I: ExactSizeIterator,
+    I::Item: Into<U>,
+{
+}
+
+impl<I, U> FusedIterator for MapIntoItems<I, U>
+where
+    I: FusedIterator,
+    I::Item: Into<U>,
+{
+}
+
+/// Calls `f` with an iterator over the converted values of `iter`, stopping at the first error
+///
+/// Values and the error are converted using [`Into::into`]. If an error is found the result of
+/// `f` is discarded and the error is returned instead:
+///
+/// ```rust
+/// use err_into::iter::process_results_into;
+///
+/// let ok: Vec<Result<u8, i8>> = vec![Ok(1), Ok(2)];
+/// let max: Result<Option<u32>, i32> = process_results_into(ok, |values| values.max());
+/// assert_eq!(max, Ok(Some(2)));
+///
+/// let err = vec![Ok(1u8), Err(-2i8), Ok(3)];
+/// let max: Result<Option<u32>, i32> = process_results_into(err, |values| values.max());
+/// assert_eq!(max, Err(-2));
+/// ```
+#[inline]
+pub fn process_results_into<I, F, R, T, E>(iter: I, f: F) -> Result<R, E>
+where
+    I: IntoIterator,
+    ResultsInto<I::IntoIter, T, E>: Iterator<Item = Result<T, E>>,
+    F: FnOnce(ProcessResultsInto<ResultsInto<I::IntoIter, T, E>, E>) -> R,
+{
+    let mut error = None;
+    let res = f(ProcessResultsInto {
+        iter: iter.into_iter().results_into(),
+        error: &mut error,
+    });
+    match error {
+        Some(err) => Err(err),
+        None => Ok(res),
+    }
+}
+
+/// Iterator passed to the closure of [`process_results_into`]
+#[derive(Debug)]
+pub struct ProcessResultsInto<'a, I, E: 'a> {
+    iter: I,
+    error: &'a mut Option<E>,
+}
+
+impl<'a, I, T, E> Iterator for ProcessResultsInto<'a, I, E>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.error.is_some() {
+            return None;
+        }
+        match self.iter.next() {
+            Some(Ok(val)) => Some(val),
+            Some(Err(err)) => {
+                *self.error = Some(err);
+                None
+            }
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.error.is_some() {
+            return (0, Some(0));
+        }
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a, I, T, E> FusedIterator for ProcessResultsInto<'a, I, E> where
+    I: FusedIterator<Item = Result<T, E>>
+{
+}
@@ -1,6 +1,12 @@
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(
+    feature = "nightly",
+    feature(async_iterator, error_generic_member_access, try_trait_v2)
+)]
 //! A `no_std` library to simpify chaining methods when you are returning a [`Result`]. It is a
-//! trivial library which sould be compatible with all environments.
+//! trivial library which sould be compatible with all environments that have a recent enough
+//! compiler (see [MSRV](#minimum-supported-rust-version)).
 //!
 //! This is specially noticeable when using crates like [`anyhow`](https://crates.io/crates/anyhow)
 //! which provide a "catch all" error type, so you need to convert all errors you recieve.
@@ -27,6 +33,14 @@
 //! let _: Result<u16, i32> = (if false { Ok(0u8) } else { Err(0i8) }).res_into();
 //! ```
 //!
+//! Or import all of them at once through the [`prelude`]:
+//!
+//! ```rust
+//! use err_into::prelude::*;
+//!
+//! let _: Result<u16, i32> = (if false { Ok(0u8) } else { Err(0i8) }).res_into();
+//! ```
+//!
 //! ## Motivating example
 //!
 //! This is slightly contrived because I don't want to depend on any libraries but showcases where
@@ -71,6 +85,197 @@
 //!         .unwrap_or(Err(0))?)
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! - `alloc`: enables combinators that need to allocate, like `ErrBoxed` or
+//!   `IteratorInto::collect_all_errs_into`, and the `err_fmt!` and `bail_fmt!` macros.
+//! - `std`: enables integrations with the standard library, like converting `HashMap`s,
+//!   capturing backtraces with `ErrTraced` or returning a `MainResult` from `main`. Implies
+//!   `alloc`.
+//! - `windows`: enables conversions from `HRESULT`s and Win32 error codes. It has no
+//!   dependencies, the codes are plain integers, and works on every target: the codes only become
+//!   raw OS errors when converted into an `io::Error` on Windows.
+//! - `macros`: enables the `err_into` attribute macro and the `FromVariants` derive.
+//! - `nightly`: enables integrations with unstable features of the standard library, like the
+//!   `async_iter` adapters, the `AutoInto` result whose `?` converts errors, or providing the
+//!   backtrace of a `Traced` error through `Error::provide`. Requires a nightly compiler.
+//!
+//! # Minimum supported Rust version
+//!
+//! The minimum supported Rust version is 1.85. The error adapters implement `core::error::Error`
+//! even without `std`, which needs 1.81, and the errors about missing conversions are reported
+//! through `#[diagnostic::do_not_recommend]`, which needs 1.85. Versions before 2.0 supported
+//! Rust 1.6.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "macros")]
+extern crate err_into_macros;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::convert::{Infallible, TryInto};
+use core::fmt;
+use core::ops::{Bound, ControlFlow, Deref, Range, RangeFrom, RangeInclusive, RangeTo};
+use core::task::Poll;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use core::convert::{From, Into};
+    pub use core::result::Result::{Err, Ok};
+
+    #[cfg(feature = "alloc")]
+    pub use alloc::format;
+
+    /// Runs the body of an `#[err_into]` function, converting its error
+    #[inline]
+    pub fn err_into_scope<T, F, E>(body: impl FnOnce() -> Result<T, F>) -> Result<T, E>
+    where
+        F: Into<E>,
+    {
+        body().map_err(Into::into)
+    }
+}
+
+/// Implements [`Clone`] and [`Debug`](fmt::Debug) for an adapter wrapping `$field` without bounds
+/// on the marker types
+macro_rules! impl_adapter {
+    ($name:ident<$inner:ident, $($M:ident),+> { $field:ident }) => {
+        impl<$inner, $($M),+> ::core::clone::Clone for $name<$inner, $($M),+>
+        where
+            $inner: ::core::clone::Clone,
+        {
+            #[inline]
+            fn clone(&self) -> Self {
+                $name {
+                    $field: self.$field.clone(),
+                    marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<$inner, $($M),+> ::core::fmt::Debug for $name<$inner, $($M),+>
+        where
+            $inner: ::core::fmt::Debug,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field(stringify!($field), &self.$field)
+                    .finish()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "nightly")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
+pub mod async_iter;
+#[cfg(feature = "nightly")]
+mod auto_into;
+mod chain;
+#[cfg(feature = "alloc")]
+mod collections;
+mod context;
+pub mod fns;
+pub mod future;
+#[cfg(feature = "alloc")]
+mod heap;
+#[cfg(feature = "std")]
+mod io;
+pub mod iter;
+mod macros;
+mod os;
+pub mod prelude;
+#[cfg(feature = "std")]
+mod process;
+mod report;
+mod validated;
+#[cfg(feature = "windows")]
+mod windows;
+
+#[cfg(feature = "nightly")]
+pub use async_iter::AsyncIteratorInto;
+#[cfg(feature = "nightly")]
+#[cfg_attr(docsrs, doc(cfg(feature = "nightly")))]
+pub use auto_into::AutoInto;
+pub use chain::{Chain, Sources};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use collections::{KeysInto, ValuesInto, VecInto};
+pub use context::{Code, ContextInto, ErrLocated, Located, Msg, WithCodeInto};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use context::{ErrTraced, Traced};
+/// Converts the error returned by a function using [`Into::into`]
+///
+/// Takes the error type used inside the body, which is converted to the one in the signature.
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// use err_into::err_into;
+///
+/// #[err_into(u8)]
+/// fn parse(val: i32) -> Result<i32, i32> {
+///     if val < 0 {
+///         return Err(0);
+///     }
+///     let half = if val % 2 == 0 { val / 2 } else { Err(1)? };
+///     Ok(half)
+/// }
+///
+/// assert_eq!(parse(-1), Err(0));
+/// assert_eq!(parse(3), Err(1));
+/// assert_eq!(parse(4), Ok(2));
+/// # }
+/// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use err_into_macros::err_into;
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "macros")] {
+/// use err_into::{ErrorInto, FromVariants};
+///
+/// #[derive(Debug, PartialEq, FromVariants)]
+/// enum Error {
+///     Small(u8),
+///     Big { source: u64 },
+///     Pair(Result<u8, u16>),
+///     #[from_variants(skip)]
+///     Other(u8),
+///     Unit,
+/// }
+///
+/// assert_eq!(Error::from(1u8), Error::Small(1));
+/// assert_eq!(Err::<(), u64>(2).err_into(), Err(Error::Big { source: 2 }));
+/// assert_eq!(Error::from(Ok::<u8, u16>(3)), Error::Pair(Ok(3)));
+/// # }
+/// ```
+#[cfg(feature = "macros")]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+pub use err_into_macros::FromVariants;
+pub use future::FutureInto;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use heap::ErrArc;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use heap::{ErrBoxed, ErrDebug, ErrIntoOwned, ErrRc, ErrToString};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use io::ErrIntoIo;
+pub use iter::IteratorInto;
+pub use os::{ErrFromErrno, Errno, FromRawOsError};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use process::{IntoExitCode, MainResult};
+pub use report::Report;
+pub use validated::{ErrorArray, ErrorArrayIntoIter, Validated};
+#[cfg(feature = "windows")]
+#[cfg_attr(docsrs, doc(cfg(feature = "windows")))]
+pub use windows::{ErrFromHresult, ErrFromWin32, FromHresult, FromWin32Error, Hresult, Win32Error};
 
 /// Maps an error using [`Into::into`]
 ///
@@ -100,8 +305,157 @@
 /// assert_eq!(handle_data_err_into(), handle_data_question_mark());
 /// assert_eq!(handle_data_err_into(), handle_data_map_err());
 /// ```
-pub trait ErrorInto<T, E> {
-    fn err_into(self) -> Result<T, E>;
+///
+/// The last parameter is the type returned, which is a [`Result`] unless the error is nested in
+/// another type, like when polling a [`Future`](core::future::Future):
+///
+/// ```rust
+/// use std::task::Poll;
+/// use err_into::ErrorInto;
+///
+/// let poll: Poll<Result<(), u8>> = Poll::Ready(Err(0));
+/// assert_eq!(poll.err_into(), Poll::Ready(Err::<(), i32>(0)));
+///
+/// // The shape returned by `Stream::poll_next`
+/// let poll: Poll<Option<Result<(), u8>>> = Poll::Ready(Some(Err(0)));
+/// assert_eq!(poll.err_into(), Poll::Ready(Some(Err::<(), i32>(0))));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "cannot convert the error of `{Self}` into `{E}`",
+    label = "the error of `{Self}` can't be converted using `Into::into`",
+    note = "`err_into` needs a `From` impl from the current error type into `{E}`",
+    note = "if the error converts through another type, use `err_into_via::<Intermediate>()` or \
+            add the missing impl with `chain_from!`"
+)]
+pub trait ErrorInto<T, E, R = Result<T, E>> {
+    fn err_into(self) -> R;
+}
+
+/// Maps an error using [`TryInto::try_into`]
+///
+/// The outer [`Result`] holds the conversion error, the inner one is the original [`Result`] with
+/// its error converted:
+///
+/// ```rust
+/// use err_into::ErrorTryInto;
+///
+/// let ok: Result<(), u16> = Ok(());
+/// assert_eq!(ok.err_try_into(), Ok(Ok::<(), u8>(())));
+///
+/// let small: Result<(), u16> = Err(4);
+/// assert_eq!(small.err_try_into(), Ok(Err::<(), u8>(4)));
+///
+/// let big: Result<(), u16> = Err(300);
+/// let res: Result<Result<(), u8>, _> = big.err_try_into();
+/// assert!(res.is_err());
+/// ```
+pub trait ErrorTryInto<T, E> {
+    /// The error returned when the conversion fails
+    type Error;
+
+    fn err_try_into(self) -> Result<Result<T, E>, Self::Error>;
+}
+
+/// Maps an error using [`Into::into`], naming the target error type in the call
+///
+/// When nothing around the call pins down the target error, [`ErrorInto::err_into`] can't be
+/// inferred and has to be moved to a binding with a type annotation. `err_into_as` takes the error
+/// type as its only generic, the value type is kept from the [`Result`]:
+///
+/// ```rust
+/// use err_into::ErrorIntoAs;
+///
+/// let res: Result<u8, u8> = Err(1);
+/// assert_eq!(res.err_into_as::<i32>().unwrap_err().pow(2), 1);
+/// ```
+pub trait ErrorIntoAs<T, F> {
+    fn err_into_as<E>(self) -> Result<T, E>
+    where
+        F: Into<E>;
+}
+
+/// Maps an error using [`Into::into`] twice, going through an intermediate type
+///
+/// [`From`] is not transitive, so an error converting into `M`, which converts into `E`, can't use
+/// [`ErrorInto::err_into`]. `err_into_via` names the intermediate type and performs both hops:
+///
+/// ```rust
+/// use err_into::ErrorIntoVia;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Low;
+/// struct Mid;
+/// #[derive(Debug, PartialEq)]
+/// struct High;
+///
+/// impl From<Low> for Mid {
+///     fn from(_: Low) -> Self {
+///         Mid
+///     }
+/// }
+///
+/// impl From<Mid> for High {
+///     fn from(_: Mid) -> Self {
+///         High
+///     }
+/// }
+///
+/// let res: Result<(), High> = Err(Low).err_into_via::<Mid>();
+/// assert_eq!(res, Err(High));
+/// ```
+///
+/// See [`chain_from!`] to add the missing [`From`] impls instead.
+pub trait ErrorIntoVia<T, F, E> {
+    fn err_into_via<M>(self) -> Result<T, E>
+    where
+        F: Into<M>,
+        M: Into<E>;
+}
+
+/// Borrows the Value of a [`Result`] and converts a reference to the Error using [`Into::into`]
+///
+/// Shorthand for `result.as_ref().map_err(Into::into)`, so read-only paths can widen the error
+/// without cloning or consuming the [`Result`]:
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use err_into::AsRefInto;
+///
+/// let res: Result<u8, String> = Err("disk full".to_string());
+/// let borrowed: Result<&u8, Cow<str>> = res.as_ref_into();
+/// assert_eq!(borrowed, Err(Cow::Borrowed("disk full")));
+///
+/// // The original is still there
+/// assert!(res.is_err());
+/// ```
+pub trait AsRefInto<T, F> {
+    fn as_ref_into<'a, E>(&'a self) -> Result<&'a T, E>
+    where
+        F: 'a,
+        &'a F: Into<E>;
+}
+
+/// Dereferences the Value of a borrowed [`Result`] or [`Option`] and converts it using
+/// [`Into::into`]
+///
+/// Shorthand for `value.as_deref().map(Into::into)`, so a `Result<String, E>` or a
+/// `Option<Box<T>>` can be converted from its borrowed target in one call:
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use std::rc::Rc;
+/// use err_into::AsDerefInto;
+///
+/// let name: Option<String> = Some("root".to_string());
+/// let shared: Option<Rc<str>> = name.as_deref_into();
+/// assert_eq!(shared.as_deref(), Some("root"));
+///
+/// let res: Result<String, u8> = Ok("disk".to_string());
+/// let borrowed: Result<Cow<str>, &u8> = res.as_deref_into();
+/// assert_eq!(borrowed, Ok(Cow::Borrowed("disk")));
+/// ```
+pub trait AsDerefInto<'a, T> {
+    fn as_deref_into(&'a self) -> T;
 }
 
 /// Maps both the Value and the Error of a [`Result`] using [`Into::into`]
@@ -114,8 +468,118 @@ pub trait ErrorInto<T, E> {
 /// let res: Result<u8, i8> = Ok(0);
 /// let _: Result<i32, i16> = res.res_into();
 /// ```
-pub trait ResultInto<T, E> {
-    fn res_into(self) -> Result<T, E>;
+///
+/// Like [`ErrorInto`], the last parameter is the type returned:
+///
+/// ```rust
+/// use std::task::Poll;
+/// use err_into::ResultInto;
+///
+/// let poll: Poll<Result<u8, i8>> = Poll::Ready(Ok(0));
+/// assert_eq!(poll.res_into(), Poll::Ready(Ok::<i32, i16>(0)));
+///
+/// // `MapInto` converts the whole `Poll` output, so use `res_into` to convert only the
+/// // value of `Stream::poll_next`
+/// let poll: Poll<Option<Result<u8, ()>>> = Poll::Ready(Some(Ok(0)));
+/// assert_eq!(poll.res_into(), Poll::Ready(Some(Ok::<i32, ()>(0))));
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "cannot convert `{Self}` into a result of `{T}` and `{E}`",
+    label = "the value or the error of `{Self}` can't be converted using `Into::into`",
+    note = "`res_into` needs `From` impls from the current value into `{T}` and from the current \
+            error into `{E}`",
+    note = "if they convert through other types, use `res_into_via::<ValueVia, ErrorVia>()` or \
+            add the missing impls with `chain_from!`"
+)]
+pub trait ResultInto<T, E, R = Result<T, E>> {
+    fn res_into(self) -> R;
+}
+
+/// Maps both the Value and the Error of a [`Result`] through intermediate types
+///
+/// Like [`ErrorIntoVia`], but converts the Value through `MT` and the Error through `ME`:
+///
+/// ```rust
+/// use err_into::ResultIntoVia;
+///
+/// struct Meters(u16);
+/// struct Code(u8);
+///
+/// impl From<Meters> for u16 {
+///     fn from(m: Meters) -> Self {
+///         m.0
+///     }
+/// }
+///
+/// impl From<Code> for u8 {
+///     fn from(c: Code) -> Self {
+///         c.0
+///     }
+/// }
+///
+/// let ok: Result<Meters, Code> = Ok(Meters(3));
+/// assert_eq!(ok.res_into_via::<u16, u8>(), Ok::<u64, i32>(3));
+///
+/// let err: Result<Meters, Code> = Err(Code(1));
+/// assert_eq!(err.res_into_via::<u16, u8>(), Err::<u64, i32>(1));
+/// ```
+pub trait ResultIntoVia<U, F, T, E> {
+    fn res_into_via<MT, ME>(self) -> Result<T, E>
+    where
+        U: Into<MT>,
+        MT: Into<T>,
+        F: Into<ME>,
+        ME: Into<E>;
+}
+
+/// Maps the Value of a [`Result`] using [`TryInto::try_into`] and the Error using [`Into::into`]
+///
+/// Both failures are merged into a single [`ResTryIntoError`] so the result can keep being
+/// chained:
+///
+/// ```rust
+/// use err_into::{ResTryIntoError, ResultTryInto};
+///
+/// let ok: Result<u16, u8> = Ok(42);
+/// assert_eq!(ok.res_try_into(), Ok::<u8, ResTryIntoError<_, i16>>(42));
+///
+/// let err: Result<u16, u8> = Err(4);
+/// assert_eq!(err.res_try_into(), Err::<u8, _>(ResTryIntoError::Err(4i16)));
+///
+/// let big: Result<u16, u8> = Ok(300);
+/// let res: Result<u8, ResTryIntoError<_, i16>> = big.res_try_into();
+/// assert!(match res {
+///     Err(ResTryIntoError::Value(_)) => true,
+///     _ => false,
+/// });
+/// ```
+pub trait ResultTryInto<T, E> {
+    /// The error returned when converting the Value fails
+    type Error;
+
+    fn res_try_into(self) -> Result<T, ResTryIntoError<Self::Error, E>>;
+}
+
+/// The error returned by [`ResultTryInto::res_try_into`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResTryIntoError<C, E> {
+    /// Converting the Value failed
+    Value(C),
+    /// The [`Result`] held an Error, converted using [`Into::into`]
+    Err(E),
+}
+
+impl<C, E> fmt::Display for ResTryIntoError<C, E>
+where
+    C: fmt::Display,
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ResTryIntoError::Value(ref err) => err.fmt(f),
+            ResTryIntoError::Err(ref err) => err.fmt(f),
+        }
+    }
 }
 
 /// Maps a value using [`Into::into`]
@@ -135,43 +599,1456 @@ pub trait ResultInto<T, E> {
 /// let map_into_std: Result<i32, ()> = result.map(Into::into);
 /// assert_eq!(map_into, map_into_std);
 /// ```
+///
+/// Tuples (up to 12 elements) are mapped element-wise:
+///
+/// ```rust
+/// use err_into::MapInto;
+///
+/// let record: (i32, i64) = (0u8, 1u16).map_into();
+/// assert_eq!(record, (0, 1));
+/// ```
+///
+/// So are arrays, without allocating:
+///
+/// ```rust
+/// use err_into::MapInto;
+///
+/// let registers: [u32; 3] = [1u8, 2, 3].map_into();
+/// assert_eq!(registers, [1, 2, 3]);
+/// ```
+///
+/// And ranges map their bounds:
+///
+/// ```rust
+/// use std::ops::Range;
+/// use err_into::MapInto;
+///
+/// let range: Range<usize> = (0u8..10u8).map_into();
+/// assert_eq!(range, 0..10);
+/// ```
+///
+/// As well as a single [`Bound`]:
+///
+/// ```rust
+/// use std::ops::Bound;
+/// use err_into::MapInto;
+///
+/// let bound: Bound<u32> = Bound::Included(1u8).map_into();
+/// assert_eq!(bound, Bound::Included(1));
+/// assert_eq!(Bound::<u8>::Unbounded.map_into(), Bound::<u32>::Unbounded);
+/// ```
+///
+/// Or the output of [`Future::poll`](core::future::Future::poll):
+///
+/// ```rust
+/// use std::task::Poll;
+/// use err_into::MapInto;
+///
+/// let poll: Poll<u32> = Poll::Ready(1u8).map_into();
+/// assert_eq!(poll, Poll::Ready(1));
+/// ```
+///
+/// Implement [`Map`] to get it for your own containers.
+#[diagnostic::on_unimplemented(
+    message = "cannot map the values of `{Self}` into `{T}`",
+    label = "the values of `{Self}` can't be converted using `Into::into`",
+    note = "`map_into` needs a `From` impl from the current value type into the target one",
+    note = "if the value converts through another type, add the missing impl with `chain_from!`"
+)]
 pub trait MapInto<T> {
     fn map_into(self) -> T;
 }
 
-impl<T, E, F> ErrorInto<T, E> for Result<T, F>
-where
-    F: Into<E>,
-{
-    fn err_into(self) -> Result<T, E> {
-        self.map_err(Into::into)
-    }
+/// Maps a value using [`Into::into`], naming the target value type in the call
+///
+/// Like [`MapInto`], but takes the target value type as its only generic so the conversion
+/// doesn't need a type annotated binding:
+///
+/// ```rust
+/// use err_into::MapIntoAs;
+///
+/// assert_eq!(Some(3u8).map_into_as::<u64>().unwrap_or_default(), 3);
+///
+/// let res: Result<u8, ()> = Ok(3);
+/// assert_eq!(res.map_into_as::<u64>().unwrap_or_default(), 3);
+/// ```
+pub trait MapIntoAs<T> {
+    /// The container holding the converted value
+    type Output<U>;
+
+    fn map_into_as<U>(self) -> Self::Output<U>
+    where
+        T: Into<U>;
 }
 
-impl<T, U, E, F> ResultInto<T, E> for Result<U, F>
-where
-    F: Into<E>,
-    U: Into<T>,
-{
-    fn res_into(self) -> Result<T, E> {
-        self.map(Into::into).map_err(Into::into)
-    }
+/// Containers whose values can be mapped with a function
+///
+/// Implementing it once gives a container [`MapInto`], [`MapIntoAs`] and [`MapTryInto`]:
+///
+/// ```rust
+/// use err_into::{Map, MapInto, MapIntoAs};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Pair<T>(T, T);
+///
+/// impl<T> Map for Pair<T> {
+///     type Item = T;
+///     type Mapped<U> = Pair<U>;
+///
+///     fn map_raw<U, F>(self, mut f: F) -> Pair<U>
+///     where
+///         F: FnMut(T) -> U,
+///     {
+///         Pair(f(self.0), f(self.1))
+///     }
+/// }
+///
+/// let pair: Pair<u32> = Pair(1u8, 2).map_into();
+/// assert_eq!(pair, Pair(1, 2));
+/// assert_eq!(Pair(1u8, 2).map_into_as::<u64>(), Pair(1, 2));
+/// ```
+///
+/// Containers that need bounds on their values, like `BinaryHeap` needing [`Ord`], or holding
+/// values of different types, like tuples, can't implement it and implement [`MapInto`] directly.
+pub trait Map {
+    /// The type of the values in the container
+    type Item;
+    /// The same container holding values of type `U`
+    type Mapped<U>;
+
+    /// Maps every value of the container with `f`
+    fn map_raw<U, F>(self, f: F) -> Self::Mapped<U>
+    where
+        F: FnMut(Self::Item) -> U;
 }
 
-impl<T, U, E> MapInto<Result<U, E>> for Result<T, E>
-where
-    T: Into<U>,
-{
-    fn map_into(self) -> Result<U, E> {
-        self.map(Into::into)
-    }
+/// Containers with two kinds of values that can be mapped with a function each
+///
+/// The two-parameter version of [`Map`]. Implementing it gives a container [`BimapInto`] and
+/// [`ResultInto`]:
+///
+/// ```rust
+/// use err_into::{Bimap, BimapInto, ResultInto};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Outcome<T, E> {
+///     Done(T),
+///     Retry(E),
+/// }
+///
+/// impl<T, E> Bimap for Outcome<T, E> {
+///     type Left = T;
+///     type Right = E;
+///     type Bimapped<L, R> = Outcome<L, R>;
+///
+///     fn bimap<L, R, F, G>(self, mut f: F, mut g: G) -> Outcome<L, R>
+///     where
+///         F: FnMut(T) -> L,
+///         G: FnMut(E) -> R,
+///     {
+///         match self {
+///             Outcome::Done(val) => Outcome::Done(f(val)),
+///             Outcome::Retry(err) => Outcome::Retry(g(err)),
+///         }
+///     }
+/// }
+///
+/// let done: Outcome<u8, i8> = Outcome::Done(1);
+/// assert_eq!(done.bimap_into::<u32, i32>(), Outcome::Done(1));
+///
+/// let retry: Outcome<u32, i32> = Outcome::<u8, i8>::Retry(-1).res_into();
+/// assert_eq!(retry, Outcome::Retry(-1));
+/// ```
+///
+/// It is implemented for every [`ResultLike`] type, like [`Result`], and for [`ControlFlow`],
+/// where the left value is the [`Break`](ControlFlow::Break) one.
+pub trait Bimap {
+    /// The type of the first kind of values
+    type Left;
+    /// The type of the second kind of values
+    type Right;
+    /// The same container holding values of types `L` and `R`
+    type Bimapped<L, R>;
+
+    /// Maps the left values with `f` and the right values with `g`
+    fn bimap<L, R, F, G>(self, f: F, g: G) -> Self::Bimapped<L, R>
+    where
+        F: FnMut(Self::Left) -> L,
+        G: FnMut(Self::Right) -> R;
 }
 
-impl<T, U> MapInto<Option<U>> for Option<T>
-where
-    T: Into<U>,
-{
-    fn map_into(self) -> Option<U> {
-        self.map(Into::into)
+/// Types equivalent to a [`Result`]
+///
+/// Implementing it gives a type [`ErrorInto`], and through [`Bimap`], [`ResultInto`] and
+/// [`BimapInto`]:
+///
+/// ```rust
+/// use err_into::{ErrorInto, ResultInto, ResultLike};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Reply<T, E> {
+///     Ack(T),
+///     Nack(E),
+/// }
+///
+/// impl<T, E> ResultLike for Reply<T, E> {
+///     type Ok = T;
+///     type Err = E;
+///     type WithResult<U, F> = Reply<U, F>;
+///
+///     fn into_result(self) -> Result<T, E> {
+///         match self {
+///             Reply::Ack(val) => Ok(val),
+///             Reply::Nack(err) => Err(err),
+///         }
+///     }
+///
+///     fn from_result(res: Result<T, E>) -> Self {
+///         match res {
+///             Ok(val) => Reply::Ack(val),
+///             Err(err) => Reply::Nack(err),
+///         }
+///     }
+/// }
+///
+/// let nack: Reply<(), i32> = Reply::<(), u8>::Nack(1).err_into();
+/// assert_eq!(nack, Reply::Nack(1));
+///
+/// let ack: Reply<u32, i32> = Reply::<u8, u8>::Ack(1).res_into();
+/// assert_eq!(ack, Reply::Ack(1));
+/// ```
+///
+/// Types that can't always be turned into a [`Result`], like `Poll<Result<T, E>>` or a result
+/// with a third variant, implement [`Bimap`] or the conversion traits directly.
+pub trait ResultLike {
+    /// The type of the Value
+    type Ok;
+    /// The type of the Error
+    type Err;
+    /// The same type holding a Value of type `T` and an Error of type `E`
+    type WithResult<T, E>: ResultLike<Ok = T, Err = E>;
+
+    /// Converts into the equivalent [`Result`]
+    fn into_result(self) -> Result<Self::Ok, Self::Err>;
+
+    /// Converts from the equivalent [`Result`]
+    fn from_result(res: Result<Self::Ok, Self::Err>) -> Self;
+}
+
+/// Maps both values of a [`Bimap`] using [`Into::into`], naming the target types in the call
+///
+/// ```rust
+/// use std::ops::ControlFlow;
+/// use err_into::BimapInto;
+///
+/// let res: Result<u8, i8> = Ok(1);
+/// assert_eq!(res.bimap_into::<u32, i32>(), Ok(1));
+///
+/// let flow: ControlFlow<u8, i8> = ControlFlow::Continue(-1);
+/// assert_eq!(flow.bimap_into::<u32, i32>(), ControlFlow::Continue(-1));
+/// ```
+pub trait BimapInto<L, R> {
+    /// The container holding the converted values
+    type Output<U, V>;
+
+    fn bimap_into<U, V>(self) -> Self::Output<U, V>
+    where
+        L: Into<U>,
+        R: Into<V>;
+}
+
+/// Maps a value using [`TryInto::try_into`]
+///
+/// Shorthand for `Option::map(self, TryInto::try_into)` and `Result::map(self, TryInto::try_into)`.
+/// Like [`MapIntoAs`], it takes the target value type as its only generic:
+///
+/// ```rust
+/// use std::convert::TryInto;
+/// use err_into::MapTryInto;
+///
+/// assert!(Some(300u16).try_map_into::<u8>().unwrap().is_err());
+/// assert_eq!(Some(42u16).try_map_into::<u8>(), Some(Ok(42)));
+///
+/// let result: Result<u16, ()> = Ok(42);
+/// let map_try_into: Result<Result<u8, _>, ()> = result.try_map_into();
+/// let map_try_into_std: Result<Result<u8, _>, ()> = result.map(TryInto::try_into);
+/// assert_eq!(map_try_into, map_try_into_std);
+/// ```
+///
+/// Implemented for every container implementing [`Map`].
+pub trait MapTryInto<T> {
+    /// The container holding the result of the conversion
+    type Output<U>;
+
+    fn try_map_into<U>(self) -> Self::Output<Result<U, <T as TryInto<U>>::Error>>
+    where
+        T: TryInto<U>;
+}
+
+/// Converts the Value of a [`Result`] into an [`Option`] using [`Into::into`]
+///
+/// Shorthand for `result.ok().map(Into::into)`
+///
+/// ```rust
+/// use err_into::OkInto;
+///
+/// let ok: Result<u8, ()> = Ok(0);
+/// assert_eq!(ok.ok_into(), Some(0i32));
+///
+/// let values: Vec<u32> = ["1", "a", "3"].iter().filter_map(|s| s.parse::<u8>().ok_into()).collect();
+/// assert_eq!(values, [1, 3]);
+/// ```
+pub trait OkInto<T> {
+    fn ok_into(self) -> Option<T>;
+}
+
+/// Converts the Error of a [`Result`] into an [`Option`] using [`Into::into`]
+///
+/// Shorthand for `result.err().map(Into::into)`, the counterpart of [`OkInto`]
+///
+/// ```rust
+/// use err_into::ErrOpt;
+///
+/// let err: Result<(), u8> = Err(0);
+/// assert_eq!(err.err_opt(), Some(0i32));
+///
+/// let results: Vec<Result<(), u8>> = vec![Err(1), Ok(()), Err(3)];
+/// let errors: Vec<u32> = results.into_iter().filter_map(ErrOpt::err_opt).collect();
+/// assert_eq!(errors, [1, 3]);
+/// ```
+pub trait ErrOpt<E> {
+    fn err_opt(self) -> Option<E>;
+}
+
+/// Transforms an [`Option`] into a [`Result`] converting the provided error using [`Into::into`]
+///
+/// Shorthand for `option.ok_or(err.into())`
+///
+/// ```rust
+/// use err_into::OkOrInto;
+///
+/// fn first(values: &[u8]) -> Result<u8, i32> {
+///     values.first().cloned().ok_or_into(-1i8)
+/// }
+///
+/// assert_eq!(first(&[1, 2]), Ok(1));
+/// assert_eq!(first(&[]), Err(-1));
+/// ```
+///
+/// Use [`OkOrInto::ok_or_else_into`] if the error is expensive to construct:
+///
+/// ```rust
+/// use err_into::OkOrInto;
+///
+/// fn expensive_err() -> i8 {
+///     -1
+/// }
+///
+/// let some: Result<u8, i32> = Some(1).ok_or_else_into(|| -> i8 { unreachable!() });
+/// assert_eq!(some, Ok(1));
+///
+/// let none: Result<u8, i32> = None.ok_or_else_into(expensive_err);
+/// assert_eq!(none, Err(-1));
+/// ```
+pub trait OkOrInto<T, E> {
+    fn ok_or_into<F>(self, err: F) -> Result<T, E>
+    where
+        F: Into<E>;
+
+    /// Lazy version of [`OkOrInto::ok_or_into`], `err` is only called on [`None`]
+    fn ok_or_else_into<F, G>(self, err: G) -> Result<T, E>
+    where
+        F: Into<E>,
+        G: FnOnce() -> F;
+}
+
+/// Transforms an [`Option`] into a [`Result`] using [`Default::default`] as the error
+///
+/// Shorthand for `option.ok_or_else(E::default)`, the error type can be named with a turbofish:
+///
+/// ```rust
+/// use err_into::OkOrDefaultErr;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// struct NotFound;
+///
+/// assert_eq!(Some(1).ok_or_default_err::<NotFound>(), Ok(1));
+/// assert_eq!(None::<u8>.ok_or_default_err(), Err(NotFound));
+/// ```
+pub trait OkOrDefaultErr<T> {
+    fn ok_or_default_err<E>(self) -> Result<T, E>
+    where
+        E: Default;
+}
+
+/// Transposes an [`Option`] of a [`Result`] (or a [`Result`] of an [`Option`]) converting both the
+/// Value and the Error using [`Into::into`]
+///
+/// Shorthand for `option.transpose().map(|o| o.map(Into::into)).map_err(Into::into)`
+///
+/// ```rust
+/// use err_into::TransposeInto;
+///
+/// let next = ["1", "a"].iter().map(|s| s.parse::<u8>()).next();
+/// let next: Result<Option<u32>, _> = next.transpose_into();
+/// assert_eq!(next, Ok::<_, std::num::ParseIntError>(Some(1)));
+///
+/// let none: Option<Result<u8, i8>> = None;
+/// assert_eq!(none.transpose_into(), Ok::<Option<u32>, i32>(None));
+///
+/// let lookup: Result<Option<u8>, i8> = Ok(Some(1));
+/// assert_eq!(lookup.transpose_into(), Some(Ok::<u32, i32>(1)));
+///
+/// let failed: Result<Option<u8>, i8> = Err(-1);
+/// assert_eq!(failed.transpose_into(), Some(Err::<u32, i32>(-1)));
+/// ```
+pub trait TransposeInto<T> {
+    fn transpose_into(self) -> T;
+}
+
+/// Flattens a nested [`Result`] converting both Errors using [`Into::into`]
+///
+/// Shorthand for `result.map_err(Into::into).and_then(|r| r.map_err(Into::into))`
+///
+/// For nested [`Option`]s the Value is converted instead, as in
+/// `option.and_then(|o| o).map(Into::into)`.
+///
+/// This is also the shape returned by awaiting the handle of a fallible task, like tokio's
+/// `JoinHandle<Result<T, F>>`, so `handle.await.flatten_into()` converts both the task's Error
+/// and the `JoinError` as long as both implement [`Into`] for the target Error.
+///
+/// ```rust
+/// use err_into::FlattenInto;
+///
+/// let ok: Result<Result<u8, u8>, i8> = Ok(Ok(1));
+/// assert_eq!(ok.flatten_into(), Ok::<u8, i32>(1));
+///
+/// let inner: Result<Result<u8, u8>, i8> = Ok(Err(2));
+/// assert_eq!(inner.flatten_into(), Err::<u8, i32>(2));
+///
+/// let outer: Result<Result<u8, u8>, i8> = Err(-3);
+/// assert_eq!(outer.flatten_into(), Err::<u8, i32>(-3));
+///
+/// let nested: Option<Option<u8>> = Some(Some(4));
+/// assert_eq!(nested.flatten_into(), Some(4u32));
+///
+/// let nested: Option<Option<u8>> = Some(None);
+/// assert_eq!(nested.flatten_into(), None::<u32>);
+/// ```
+pub trait FlattenInto<T> {
+    fn flatten_into(self) -> T;
+}
+
+/// Maps the [`Break`](ControlFlow::Break) value of a [`ControlFlow`] using [`Into::into`]
+///
+/// ```rust
+/// use std::ops::ControlFlow;
+/// use err_into::BreakInto;
+///
+/// let flow: ControlFlow<u8, ()> = ControlFlow::Break(1);
+/// assert_eq!(flow.break_into(), ControlFlow::<i32, ()>::Break(1));
+/// ```
+pub trait BreakInto<B, C> {
+    fn break_into(self) -> ControlFlow<B, C>;
+}
+
+/// Maps the [`Continue`](ControlFlow::Continue) value of a [`ControlFlow`] using [`Into::into`]
+///
+/// ```rust
+/// use std::ops::ControlFlow;
+/// use err_into::ContinueInto;
+///
+/// let flow: ControlFlow<(), u8> = ControlFlow::Continue(1);
+/// assert_eq!(flow.continue_into(), ControlFlow::<(), i32>::Continue(1));
+/// ```
+pub trait ContinueInto<B, C> {
+    fn continue_into(self) -> ControlFlow<B, C>;
+}
+
+/// Maps both the [`Break`](ControlFlow::Break) and [`Continue`](ControlFlow::Continue) values of
+/// a [`ControlFlow`] using [`Into::into`]
+///
+/// ```rust
+/// use std::ops::ControlFlow;
+/// use err_into::FlowInto;
+///
+/// let flow: ControlFlow<u8, i8> = ControlFlow::Continue(-1);
+/// assert_eq!(flow.flow_into(), ControlFlow::<i32, i16>::Continue(-1));
+///
+/// let flow: ControlFlow<u8, i8> = ControlFlow::Break(1);
+/// assert_eq!(flow.flow_into(), ControlFlow::<i32, i16>::Break(1));
+/// ```
+pub trait FlowInto<B, C> {
+    fn flow_into(self) -> ControlFlow<B, C>;
+}
+
+/// Transforms a [`Result`] into a [`ControlFlow`] converting the Error using [`Into::into`]
+///
+/// [`Err`] becomes [`Break`](ControlFlow::Break) and [`Ok`] becomes
+/// [`Continue`](ControlFlow::Continue), mirroring how `?` treats them:
+///
+/// ```rust
+/// use std::ops::ControlFlow;
+/// use err_into::IntoControlFlowInto;
+///
+/// let sum = [1u8, 2, 3].iter().try_fold(0u32, |acc, &x| {
+///     let checked: Result<u32, u8> = if x < 3 { Ok(acc + u32::from(x)) } else { Err(x) };
+///     checked.into_control_flow_into()
+/// });
+/// assert_eq!(sum, ControlFlow::<i32, u32>::Break(3));
+/// ```
+pub trait IntoControlFlowInto<B, C> {
+    fn into_control_flow_into(self) -> ControlFlow<B, C>;
+}
+
+/// Transforms a [`ControlFlow`] into a [`Result`] converting the
+/// [`Break`](ControlFlow::Break) value using [`Into::into`]
+///
+/// [`Break`](ControlFlow::Break) becomes [`Err`] and [`Continue`](ControlFlow::Continue) becomes
+/// [`Ok`], the inverse of [`IntoControlFlowInto`]:
+///
+/// ```rust
+/// use std::ops::ControlFlow;
+/// use err_into::IntoResultInto;
+///
+/// fn first_even(values: &[u8]) -> Result<(), i32> {
+///     values
+///         .iter()
+///         .try_for_each(|&x| match x % 2 {
+///             0 => ControlFlow::Break(x),
+///             _ => ControlFlow::Continue(()),
+///         })
+///         .into_result_into()
+/// }
+///
+/// assert_eq!(first_even(&[1, 3, 4]), Err(4));
+/// assert_eq!(first_even(&[1, 3]), Ok(()));
+/// ```
+pub trait IntoResultInto<T, E> {
+    fn into_result_into(self) -> Result<T, E>;
+}
+
+/// Extracts the Value of a [`Result`] that can't fail, converting it using [`Into::into`]
+///
+/// A stable, converting version of the unstable `Result::into_ok`, named differently so it
+/// doesn't collide with it once stabilized.
+///
+/// ```rust
+/// use std::convert::Infallible;
+/// use err_into::IntoOkInto;
+///
+/// let res: Result<u8, Infallible> = Ok(1);
+/// let val: u32 = res.into_ok_into();
+/// assert_eq!(val, 1);
+/// ```
+pub trait IntoOkInto<T> {
+    fn into_ok_into(self) -> T;
+}
+
+/// Extracts the Error of a [`Result`] that can't succeed, converting it using [`Into::into`]
+///
+/// A stable, converting version of the unstable `Result::into_err`, named differently so it
+/// doesn't collide with it once stabilized.
+///
+/// ```rust
+/// use std::convert::Infallible;
+/// use err_into::IntoErrInto;
+///
+/// let res: Result<Infallible, u8> = Err(1);
+/// let err: u32 = res.into_err_into();
+/// assert_eq!(err, 1);
+/// ```
+pub trait IntoErrInto<E> {
+    fn into_err_into(self) -> E;
+}
+
+/// Joins a tuple of [`Result`]s into a [`Result`] of a tuple converting the Errors using
+/// [`Into::into`]
+///
+/// Implemented for tuples of up to 8 elements, the first Error is returned:
+///
+/// ```rust
+/// use err_into::AllInto;
+///
+/// let all: Result<(u8, u16), i32> = (Ok::<u8, u8>(1), Ok::<u16, i16>(2)).all_into();
+/// assert_eq!(all, Ok((1, 2)));
+///
+/// let all: Result<(u8, u16), i32> = (Err::<u8, u8>(1), Err::<u16, i16>(2)).all_into();
+/// assert_eq!(all, Err(1));
+/// ```
+pub trait AllInto<T, E> {
+    fn all_into(self) -> Result<T, E>;
+}
+
+/// Zips two [`Option`]s converting both Values using [`Into::into`]
+///
+/// ```rust
+/// use err_into::ZipInto;
+///
+/// let zipped = Some(1u8).zip_into::<(u32, u64)>(Some(2u16));
+/// assert_eq!(zipped, Some((1, 2)));
+/// assert_eq!(None::<u8>.zip_into::<(u32, u64)>(Some(2u16)), None);
+///
+/// let sum = Some(1u8).zip_with_into(Some(2u16), |a: u32, b: u32| a + b);
+/// assert_eq!(sum, Some(3));
+/// ```
+pub trait ZipInto<T, U> {
+    /// Shorthand for `self.zip(other).map_into()`
+    fn zip_into<P>(self, other: Option<U>) -> Option<P>
+    where
+        (T, U): MapInto<P>;
+
+    /// Zips the Values with `f`, after converting them using [`Into::into`]
+    fn zip_with_into<V, W, R, F>(self, other: Option<U>, f: F) -> Option<R>
+    where
+        T: Into<V>,
+        U: Into<W>,
+        F: FnOnce(V, W) -> R;
+}
+
+/// Zips two [`Result`]s keeping both Errors, converting them using [`Into::into`]
+///
+/// The Error holds the Error of each [`Result`] that failed, so callers can report every failure:
+///
+/// ```rust
+/// use err_into::ZipErrsInto;
+///
+/// let a: Result<u8, u8> = Ok(1);
+/// let b: Result<u16, i8> = Ok(2);
+/// assert_eq!(a.zip_errs_into::<i32, i32>(b), Ok((1, 2)));
+///
+/// let a: Result<u8, u8> = Err(1);
+/// let b: Result<u16, i8> = Ok(2);
+/// assert_eq!(a.zip_errs_into::<i32, i32>(b), Err((Some(1), None)));
+///
+/// let a: Result<u8, u8> = Err(1);
+/// let b: Result<u16, i8> = Err(-2);
+/// assert_eq!(a.zip_errs_into::<i32, i32>(b), Err((Some(1), Some(-2))));
+/// ```
+pub trait ZipErrsInto<T, F, U, G> {
+    fn zip_errs_into<E1, E2>(self, other: Result<U, G>) -> Result<(T, U), (Option<E1>, Option<E2>)>
+    where
+        F: Into<E1>,
+        G: Into<E2>;
+}
+
+/// Converts a value using [`Into::into`], naming the target type in the call
+///
+/// `Into::into` can't be turbofished, so the target must come from a type annotation, which
+/// breaks method chains. `conv` takes it as a method generic instead:
+///
+/// ```rust
+/// use err_into::Conv;
+///
+/// let len = 3u8.conv::<u64>().pow(20);
+/// assert_eq!(len, 3_486_784_401);
+///
+/// let halves: Vec<f64> = [1u8, 3].iter().map(|&n| n.conv::<f64>() / 2.0).collect();
+/// assert_eq!(halves, [0.5, 1.5]);
+/// ```
+pub trait Conv: Sized {
+    fn conv<T>(self) -> T
+    where
+        Self: Into<T>;
+}
+
+impl<U> Conv for U {
+    #[inline]
+    fn conv<T>(self) -> T
+    where
+        Self: Into<T>,
+    {
+        self.into()
+    }
+}
+
+/// Converts a value using [`TryInto::try_into`], naming the target type in the call
+///
+/// The fallible sibling of [`Conv`], so narrowing conversions can feed straight into
+/// [`ErrorInto::err_into`]:
+///
+/// ```rust
+/// use std::num::TryFromIntError;
+/// use err_into::{ErrorInto, TryConv};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Overflow;
+///
+/// impl From<TryFromIntError> for Overflow {
+///     fn from(_: TryFromIntError) -> Self {
+///         Overflow
+///     }
+/// }
+///
+/// let small: Result<u8, Overflow> = 200u16.try_conv::<u8>().err_into();
+/// assert_eq!(small, Ok(200));
+///
+/// let big: Result<u8, Overflow> = 300u16.try_conv::<u8>().err_into();
+/// assert_eq!(big, Err(Overflow));
+/// ```
+pub trait TryConv: Sized {
+    fn try_conv<T>(self) -> Result<T, <Self as TryInto<T>>::Error>
+    where
+        Self: TryInto<T>;
+}
+
+impl<U> TryConv for U {
+    #[inline]
+    fn try_conv<T>(self) -> Result<T, <Self as TryInto<T>>::Error>
+    where
+        Self: TryInto<T>,
+    {
+        self.try_into()
+    }
+}
+
+/// Converts a value using [`Into::into`] twice, going through an intermediate type
+///
+/// The general version of [`ErrorIntoVia`], the intermediate type is named in the call:
+///
+/// ```rust
+/// use err_into::IntoVia;
+///
+/// struct Celsius(i16);
+///
+/// impl From<Celsius> for i16 {
+///     fn from(c: Celsius) -> Self {
+///         c.0
+///     }
+/// }
+///
+/// let raw: i64 = Celsius(-4).into_via::<i16>();
+/// assert_eq!(raw, -4);
+/// ```
+pub trait IntoVia<T>: Sized {
+    fn into_via<M>(self) -> T
+    where
+        Self: Into<M>,
+        M: Into<T>;
+}
+
+impl<U, T> IntoVia<T> for U {
+    #[inline]
+    fn into_via<M>(self) -> T
+    where
+        Self: Into<M>,
+        M: Into<T>,
+    {
+        Into::<M>::into(self).into()
+    }
+}
+
+/// Umbrella trait for [`ErrorInto`], [`ResultInto`] and [`MapInto`]
+///
+/// Useful as a single bound in generic code. It is implemented for every type implementing the
+/// three traits; to call their methods on concrete types import them (or the [`prelude`]).
+///
+/// ```rust
+/// use err_into::prelude::*;
+///
+/// fn widen<R: IntoExt<u8, i32, Result<u16, u8>>>(res: R) -> Result<u8, i32> {
+///     res.err_into()
+/// }
+///
+/// assert_eq!(widen(Err::<u8, u8>(1)), Err(1));
+/// ```
+pub trait IntoExt<T, E, U>: ErrorInto<T, E> + ResultInto<T, E> + MapInto<U> {}
+
+impl<R, T, E, U> IntoExt<T, E, U> for R where R: ErrorInto<T, E> + ResultInto<T, E> + MapInto<U> {}
+
+#[diagnostic::do_not_recommend]
+impl<R, E> ErrorInto<R::Ok, E, R::WithResult<R::Ok, E>> for R
+where
+    R: ResultLike,
+    R::Err: Into<E>,
+{
+    #[inline]
+    fn err_into(self) -> R::WithResult<R::Ok, E> {
+        ResultLike::from_result(self.into_result().map_err(Into::into))
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<T, E, F> ErrorInto<T, E, Poll<Result<T, E>>> for Poll<Result<T, F>>
+where
+    F: Into<E>,
+{
+    #[inline]
+    fn err_into(self) -> Poll<Result<T, E>> {
+        self.map_err(Into::into)
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<T, E, F> ErrorInto<T, E, Poll<Option<Result<T, E>>>> for Poll<Option<Result<T, F>>>
+where
+    F: Into<E>,
+{
+    #[inline]
+    fn err_into(self) -> Poll<Option<Result<T, E>>> {
+        self.map_err(Into::into)
+    }
+}
+
+impl<T, E, F> ErrorTryInto<T, E> for Result<T, F>
+where
+    F: TryInto<E>,
+{
+    type Error = F::Error;
+
+    #[inline]
+    fn err_try_into(self) -> Result<Result<T, E>, F::Error> {
+        match self {
+            Ok(val) => Ok(Ok(val)),
+            Err(err) => err.try_into().map(Err),
+        }
+    }
+}
+
+impl<T, F> ErrorIntoAs<T, F> for Result<T, F> {
+    #[inline]
+    fn err_into_as<E>(self) -> Result<T, E>
+    where
+        F: Into<E>,
+    {
+        self.map_err(Into::into)
+    }
+}
+
+impl<T, F, E> ErrorIntoVia<T, F, E> for Result<T, F> {
+    #[inline]
+    fn err_into_via<M>(self) -> Result<T, E>
+    where
+        F: Into<M>,
+        M: Into<E>,
+    {
+        self.map_err(|err| err.into_via::<M>())
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<C, T, E> ResultInto<T, E, C::Bimapped<T, E>> for C
+where
+    C: Bimap,
+    C::Left: Into<T>,
+    C::Right: Into<E>,
+{
+    #[inline]
+    fn res_into(self) -> C::Bimapped<T, E> {
+        self.bimap(Into::into, Into::into)
+    }
+}
+
+impl<C> BimapInto<C::Left, C::Right> for C
+where
+    C: Bimap,
+{
+    type Output<U, V> = C::Bimapped<U, V>;
+
+    #[inline]
+    fn bimap_into<U, V>(self) -> C::Bimapped<U, V>
+    where
+        C::Left: Into<U>,
+        C::Right: Into<V>,
+    {
+        self.bimap(Into::into, Into::into)
+    }
+}
+
+impl<C> Bimap for C
+where
+    C: ResultLike,
+{
+    type Left = C::Ok;
+    type Right = C::Err;
+    type Bimapped<L, R> = C::WithResult<L, R>;
+
+    #[inline]
+    fn bimap<L, R, F, G>(self, mut f: F, mut g: G) -> C::WithResult<L, R>
+    where
+        F: FnMut(C::Ok) -> L,
+        G: FnMut(C::Err) -> R,
+    {
+        // Rebuilding the result in each arm compiles like `map(f).map_err(g)`, going through an
+        // intermediate `Result` doesn't
+        match self.into_result() {
+            Ok(value) => ResultLike::from_result(Ok(f(value))),
+            Err(err) => ResultLike::from_result(Err(g(err))),
+        }
+    }
+}
+
+impl<T, E> ResultLike for Result<T, E> {
+    type Ok = T;
+    type Err = E;
+    type WithResult<U, F> = Result<U, F>;
+
+    #[inline]
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+
+    #[inline]
+    fn from_result(res: Result<T, E>) -> Self {
+        res
+    }
+}
+
+impl<B, C> Bimap for ControlFlow<B, C> {
+    type Left = B;
+    type Right = C;
+    type Bimapped<L, R> = ControlFlow<L, R>;
+
+    #[inline]
+    fn bimap<L, R, F, G>(self, mut f: F, mut g: G) -> ControlFlow<L, R>
+    where
+        F: FnMut(B) -> L,
+        G: FnMut(C) -> R,
+    {
+        match self {
+            ControlFlow::Break(val) => ControlFlow::Break(f(val)),
+            ControlFlow::Continue(val) => ControlFlow::Continue(g(val)),
+        }
+    }
+}
+
+impl<T, F> AsRefInto<T, F> for Result<T, F> {
+    #[inline]
+    fn as_ref_into<'a, E>(&'a self) -> Result<&'a T, E>
+    where
+        F: 'a,
+        &'a F: Into<E>,
+    {
+        self.as_ref().map_err(Into::into)
+    }
+}
+
+impl<'a, T, U, E> AsDerefInto<'a, Result<U, &'a E>> for Result<T, E>
+where
+    T: Deref,
+    T::Target: 'a,
+    &'a T::Target: Into<U>,
+{
+    #[inline]
+    fn as_deref_into(&'a self) -> Result<U, &'a E> {
+        self.as_deref().map(Into::into)
+    }
+}
+
+impl<'a, T, U> AsDerefInto<'a, Option<U>> for Option<T>
+where
+    T: Deref,
+    T::Target: 'a,
+    &'a T::Target: Into<U>,
+{
+    #[inline]
+    fn as_deref_into(&'a self) -> Option<U> {
+        self.as_deref().map(Into::into)
+    }
+}
+
+impl<U, F, T, E> ResultIntoVia<U, F, T, E> for Result<U, F> {
+    #[inline]
+    fn res_into_via<MT, ME>(self) -> Result<T, E>
+    where
+        U: Into<MT>,
+        MT: Into<T>,
+        F: Into<ME>,
+        ME: Into<E>,
+    {
+        self.map(IntoVia::into_via::<MT>)
+            .map_err(IntoVia::into_via::<ME>)
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<T, U, E, F> ResultInto<T, E, Poll<Result<T, E>>> for Poll<Result<U, F>>
+where
+    F: Into<E>,
+    U: Into<T>,
+{
+    #[inline]
+    fn res_into(self) -> Poll<Result<T, E>> {
+        self.map_ok(Into::into).map_err(Into::into)
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<T, U, E, F> ResultInto<T, E, Poll<Option<Result<T, E>>>> for Poll<Option<Result<U, F>>>
+where
+    F: Into<E>,
+    U: Into<T>,
+{
+    #[inline]
+    fn res_into(self) -> Poll<Option<Result<T, E>>> {
+        self.map_ok(Into::into).map_err(Into::into)
+    }
+}
+
+impl<T, U, E, F> ResultTryInto<T, E> for Result<U, F>
+where
+    F: Into<E>,
+    U: TryInto<T>,
+{
+    type Error = U::Error;
+
+    #[inline]
+    fn res_try_into(self) -> Result<T, ResTryIntoError<U::Error, E>> {
+        match self {
+            Ok(val) => val.try_into().map_err(ResTryIntoError::Value),
+            Err(err) => Err(ResTryIntoError::Err(err.into())),
+        }
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<C, D> MapInto<D> for C
+where
+    C: Map<Mapped<<D as Map>::Item> = D>,
+    C::Item: Into<D::Item>,
+    D: Map,
+{
+    #[inline]
+    fn map_into(self) -> D {
+        self.map_raw(Into::into)
+    }
+}
+
+impl<C> MapIntoAs<C::Item> for C
+where
+    C: Map,
+{
+    type Output<U> = C::Mapped<U>;
+
+    #[inline]
+    fn map_into_as<U>(self) -> C::Mapped<U>
+    where
+        C::Item: Into<U>,
+    {
+        self.map_raw(Into::into)
+    }
+}
+
+impl<T, E> Map for Result<T, E> {
+    type Item = T;
+    type Mapped<U> = Result<U, E>;
+
+    #[inline]
+    fn map_raw<U, F>(self, f: F) -> Result<U, E>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+impl<T> Map for Option<T> {
+    type Item = T;
+    type Mapped<U> = Option<U>;
+
+    #[inline]
+    fn map_raw<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+impl<T, const N: usize> Map for [T; N] {
+    type Item = T;
+    type Mapped<U> = [U; N];
+
+    #[inline]
+    fn map_raw<U, F>(self, f: F) -> [U; N]
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+impl<T> Map for Range<T> {
+    type Item = T;
+    type Mapped<U> = Range<U>;
+
+    #[inline]
+    fn map_raw<U, F>(self, mut f: F) -> Range<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        f(self.start)..f(self.end)
+    }
+}
+
+impl<T> Map for RangeInclusive<T> {
+    type Item = T;
+    type Mapped<U> = RangeInclusive<U>;
+
+    #[inline]
+    fn map_raw<U, F>(self, mut f: F) -> RangeInclusive<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        let (start, end) = self.into_inner();
+        f(start)..=f(end)
+    }
+}
+
+impl<T> Map for RangeFrom<T> {
+    type Item = T;
+    type Mapped<U> = RangeFrom<U>;
+
+    #[inline]
+    fn map_raw<U, F>(self, mut f: F) -> RangeFrom<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        f(self.start)..
+    }
+}
+
+impl<T> Map for RangeTo<T> {
+    type Item = T;
+    type Mapped<U> = RangeTo<U>;
+
+    #[inline]
+    fn map_raw<U, F>(self, mut f: F) -> RangeTo<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        ..f(self.end)
+    }
+}
+
+impl<T> Map for Bound<T> {
+    type Item = T;
+    type Mapped<U> = Bound<U>;
+
+    #[inline]
+    fn map_raw<U, F>(self, mut f: F) -> Bound<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        match self {
+            Bound::Included(val) => Bound::Included(f(val)),
+            Bound::Excluded(val) => Bound::Excluded(f(val)),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
+impl<T> Map for Poll<T> {
+    type Item = T;
+    type Mapped<U> = Poll<U>;
+
+    #[inline]
+    fn map_raw<U, F>(self, f: F) -> Poll<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        self.map(f)
+    }
+}
+
+macro_rules! tuple_map_into {
+    ($($T:ident $U:ident $idx:tt),+) => {
+        #[diagnostic::do_not_recommend]
+        impl<$($T, $U),+> MapInto<($($U,)+)> for ($($T,)+)
+        where
+            $($T: Into<$U>),+
+        {
+            #[inline]
+            fn map_into(self) -> ($($U,)+) {
+                ($(self.$idx.into(),)+)
+            }
+        }
+    };
+}
+
+tuple_map_into!(T0 U0 0);
+tuple_map_into!(T0 U0 0, T1 U1 1);
+tuple_map_into!(T0 U0 0, T1 U1 1, T2 U2 2);
+tuple_map_into!(T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3);
+tuple_map_into!(T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4);
+tuple_map_into!(T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5);
+tuple_map_into!(T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6);
+tuple_map_into!(T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6, T7 U7 7);
+tuple_map_into!(T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6, T7 U7 7, T8 U8 8);
+tuple_map_into!(
+    T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6, T7 U7 7, T8 U8 8, T9 U9 9
+);
+tuple_map_into!(
+    T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6, T7 U7 7, T8 U8 8, T9 U9 9,
+    T10 U10 10
+);
+tuple_map_into!(
+    T0 U0 0, T1 U1 1, T2 U2 2, T3 U3 3, T4 U4 4, T5 U5 5, T6 U6 6, T7 U7 7, T8 U8 8, T9 U9 9,
+    T10 U10 10, T11 U11 11
+);
+
+impl<C> MapTryInto<C::Item> for C
+where
+    C: Map,
+{
+    type Output<U> = C::Mapped<U>;
+
+    #[inline]
+    fn try_map_into<U>(self) -> C::Mapped<Result<U, <C::Item as TryInto<U>>::Error>>
+    where
+        C::Item: TryInto<U>,
+    {
+        self.map_raw(TryInto::try_into)
+    }
+}
+
+impl<T, U, E> OkInto<U> for Result<T, E>
+where
+    T: Into<U>,
+{
+    #[inline]
+    fn ok_into(self) -> Option<U> {
+        self.ok().map(Into::into)
+    }
+}
+
+impl<T, E, F> ErrOpt<E> for Result<T, F>
+where
+    F: Into<E>,
+{
+    #[inline]
+    fn err_opt(self) -> Option<E> {
+        self.err().map(Into::into)
+    }
+}
+
+impl<T, E> OkOrInto<T, E> for Option<T> {
+    #[inline]
+    fn ok_or_into<F>(self, err: F) -> Result<T, E>
+    where
+        F: Into<E>,
+    {
+        self.ok_or_else(|| err.into())
+    }
+
+    #[inline]
+    fn ok_or_else_into<F, G>(self, err: G) -> Result<T, E>
+    where
+        F: Into<E>,
+        G: FnOnce() -> F,
+    {
+        self.ok_or_else(|| err().into())
+    }
+}
+
+impl<T> OkOrDefaultErr<T> for Option<T> {
+    #[inline]
+    fn ok_or_default_err<E>(self) -> Result<T, E>
+    where
+        E: Default,
+    {
+        self.ok_or_else(E::default)
+    }
+}
+
+impl<T, U, E, F> TransposeInto<Result<Option<U>, F>> for Option<Result<T, E>>
+where
+    T: Into<U>,
+    E: Into<F>,
+{
+    #[inline]
+    fn transpose_into(self) -> Result<Option<U>, F> {
+        match self {
+            Some(Ok(val)) => Ok(Some(val.into())),
+            Some(Err(err)) => Err(err.into()),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T, U, E, F> TransposeInto<Option<Result<U, F>>> for Result<Option<T>, E>
+where
+    T: Into<U>,
+    E: Into<F>,
+{
+    #[inline]
+    fn transpose_into(self) -> Option<Result<U, F>> {
+        match self {
+            Ok(Some(val)) => Some(Ok(val.into())),
+            Ok(None) => None,
+            Err(err) => Some(Err(err.into())),
+        }
+    }
+}
+
+impl<T, E, F, G> FlattenInto<Result<T, E>> for Result<Result<T, F>, G>
+where
+    F: Into<E>,
+    G: Into<E>,
+{
+    #[inline]
+    fn flatten_into(self) -> Result<T, E> {
+        match self {
+            Ok(Ok(val)) => Ok(val),
+            Ok(Err(err)) => Err(err.into()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+impl<T, U> FlattenInto<Option<U>> for Option<Option<T>>
+where
+    T: Into<U>,
+{
+    #[inline]
+    fn flatten_into(self) -> Option<U> {
+        self.and_then(|opt| opt).map(Into::into)
+    }
+}
+
+impl<B, C, U> BreakInto<B, C> for ControlFlow<U, C>
+where
+    U: Into<B>,
+{
+    #[inline]
+    fn break_into(self) -> ControlFlow<B, C> {
+        match self {
+            ControlFlow::Break(val) => ControlFlow::Break(val.into()),
+            ControlFlow::Continue(val) => ControlFlow::Continue(val),
+        }
+    }
+}
+
+impl<B, C, U> ContinueInto<B, C> for ControlFlow<B, U>
+where
+    U: Into<C>,
+{
+    #[inline]
+    fn continue_into(self) -> ControlFlow<B, C> {
+        match self {
+            ControlFlow::Break(val) => ControlFlow::Break(val),
+            ControlFlow::Continue(val) => ControlFlow::Continue(val.into()),
+        }
+    }
+}
+
+impl<B, C, U, V> FlowInto<B, C> for ControlFlow<U, V>
+where
+    U: Into<B>,
+    V: Into<C>,
+{
+    #[inline]
+    fn flow_into(self) -> ControlFlow<B, C> {
+        self.bimap(Into::into, Into::into)
+    }
+}
+
+impl<B, C, E> IntoControlFlowInto<B, C> for Result<C, E>
+where
+    E: Into<B>,
+{
+    #[inline]
+    fn into_control_flow_into(self) -> ControlFlow<B, C> {
+        match self {
+            Ok(val) => ControlFlow::Continue(val),
+            Err(err) => ControlFlow::Break(err.into()),
+        }
+    }
+}
+
+impl<T, E, B> IntoResultInto<T, E> for ControlFlow<B, T>
+where
+    B: Into<E>,
+{
+    #[inline]
+    fn into_result_into(self) -> Result<T, E> {
+        match self {
+            ControlFlow::Continue(val) => Ok(val),
+            ControlFlow::Break(val) => Err(val.into()),
+        }
+    }
+}
+
+impl<T, U> IntoOkInto<T> for Result<U, Infallible>
+where
+    U: Into<T>,
+{
+    #[inline]
+    fn into_ok_into(self) -> T {
+        match self {
+            Ok(val) => val.into(),
+            Err(never) => match never {},
+        }
+    }
+}
+
+impl<E, F> IntoErrInto<E> for Result<Infallible, F>
+where
+    F: Into<E>,
+{
+    #[inline]
+    fn into_err_into(self) -> E {
+        match self {
+            Ok(never) => match never {},
+            Err(err) => err.into(),
+        }
+    }
+}
+
+macro_rules! tuple_all_into {
+    ($($T:ident $F:ident $idx:tt),+) => {
+        impl<E, $($T, $F),+> AllInto<($($T,)+), E> for ($(Result<$T, $F>,)+)
+        where
+            $($F: Into<E>),+
+        {
+            #[inline]
+            fn all_into(self) -> Result<($($T,)+), E> {
+                Ok(($(self.$idx.err_into()?,)+))
+            }
+        }
+    };
+}
+
+tuple_all_into!(T0 F0 0);
+tuple_all_into!(T0 F0 0, T1 F1 1);
+tuple_all_into!(T0 F0 0, T1 F1 1, T2 F2 2);
+tuple_all_into!(T0 F0 0, T1 F1 1, T2 F2 2, T3 F3 3);
+tuple_all_into!(T0 F0 0, T1 F1 1, T2 F2 2, T3 F3 3, T4 F4 4);
+tuple_all_into!(T0 F0 0, T1 F1 1, T2 F2 2, T3 F3 3, T4 F4 4, T5 F5 5);
+tuple_all_into!(T0 F0 0, T1 F1 1, T2 F2 2, T3 F3 3, T4 F4 4, T5 F5 5, T6 F6 6);
+tuple_all_into!(T0 F0 0, T1 F1 1, T2 F2 2, T3 F3 3, T4 F4 4, T5 F5 5, T6 F6 6, T7 F7 7);
+
+impl<T, U> ZipInto<T, U> for Option<T> {
+    #[inline]
+    fn zip_into<P>(self, other: Option<U>) -> Option<P>
+    where
+        (T, U): MapInto<P>,
+    {
+        self.zip(other).map(MapInto::map_into)
+    }
+
+    #[inline]
+    fn zip_with_into<V, W, R, F>(self, other: Option<U>, f: F) -> Option<R>
+    where
+        T: Into<V>,
+        U: Into<W>,
+        F: FnOnce(V, W) -> R,
+    {
+        self.zip(other).map(|(a, b)| f(a.into(), b.into()))
+    }
+}
+
+impl<T, F, U, G> ZipErrsInto<T, F, U, G> for Result<T, F> {
+    #[inline]
+    fn zip_errs_into<E1, E2>(self, other: Result<U, G>) -> Result<(T, U), (Option<E1>, Option<E2>)>
+    where
+        F: Into<E1>,
+        G: Into<E2>,
+    {
+        match (self, other) {
+            (Ok(a), Ok(b)) => Ok((a, b)),
+            (a, b) => Err((a.err_opt(), b.err_opt())),
+        }
     }
 }
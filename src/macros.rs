@@ -0,0 +1,193 @@
+//! Declarative macros
+
+/// Generates the transitive [`From`] impls of a chain of conversions
+///
+/// `chain_from!(A => B => C)` implements `From<A> for C` by converting through `B`, so it requires
+/// `From<A> for B` and `From<B> for C`. Longer chains get an impl for every pair of types that are
+/// not already adjacent.
+///
+/// ```rust
+/// use err_into::{chain_from, ErrorInto};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Low;
+/// #[derive(Debug, PartialEq)]
+/// struct Mid(Low);
+/// #[derive(Debug, PartialEq)]
+/// struct High(Mid);
+///
+/// impl From<Low> for Mid {
+///     fn from(low: Low) -> Self {
+///         Mid(low)
+///     }
+/// }
+///
+/// impl From<Mid> for High {
+///     fn from(mid: Mid) -> Self {
+///         High(mid)
+///     }
+/// }
+///
+/// chain_from!(Low => Mid => High);
+///
+/// assert_eq!(Err::<(), _>(Low).err_into(), Err(High(Mid(Low))));
+/// ```
+#[macro_export]
+macro_rules! chain_from {
+    ($from:ty) => {};
+    ($from:ty => $($rest:ty)=>+) => {
+        $crate::chain_from!(@from $from => $($rest)=>+);
+        $crate::chain_from!($($rest)=>+);
+    };
+    (@from $from:ty => $via:ty) => {};
+    (@from $from:ty => $via:ty => $to:ty $(=> $rest:ty)*) => {
+        impl $crate::__private::From<$from> for $to {
+            #[inline]
+            fn from(value: $from) -> Self {
+                <$to as $crate::__private::From<$via>>::from($crate::__private::From::from(value))
+            }
+        }
+        $crate::chain_from!(@from $from => $to $(=> $rest)*);
+    };
+}
+
+/// Returns early with an error converted using [`Into::into`]
+///
+/// Shorthand for `return Err(err.into())`, the same conversion `?` applies:
+///
+/// ```rust
+/// use err_into::bail_into;
+///
+/// fn check(val: i32) -> Result<i32, i64> {
+///     if val < 0 {
+///         bail_into!(val);
+///     }
+///     Ok(val)
+/// }
+///
+/// assert_eq!(check(-1), Err(-1i64));
+/// assert_eq!(check(1), Ok(1));
+/// ```
+#[macro_export]
+macro_rules! bail_into {
+    ($err:expr $(,)?) => {
+        return $crate::__private::Err($crate::__private::Into::into($err))
+    };
+}
+
+/// Returns early with an error converted using [`Into::into`] if a condition is not met
+///
+/// Shorthand for `if !cond { return Err(err.into()) }`:
+///
+/// ```rust
+/// use err_into::ensure_into;
+///
+/// fn half(val: u8) -> Result<u8, u16> {
+///     ensure_into!(val % 2 == 0, val);
+///     Ok(val / 2)
+/// }
+///
+/// assert_eq!(half(3), Err(3u16));
+/// assert_eq!(half(4), Ok(2));
+/// ```
+#[macro_export]
+macro_rules! ensure_into {
+    ($cond:expr, $err:expr $(,)?) => {
+        if !$cond {
+            $crate::bail_into!($err);
+        }
+    };
+}
+
+/// Formats an error message and converts it using [`Into::into`]
+///
+/// Shorthand for `format!(...).into()`, for error types that can be built from a
+/// [`String`](alloc::string::String):
+///
+/// ```rust
+/// use err_into::err_fmt;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Error(String);
+///
+/// impl From<String> for Error {
+///     fn from(msg: String) -> Self {
+///         Error(msg)
+///     }
+/// }
+///
+/// let err: Error = err_fmt!("failed to open {}: {}", "config.toml", 2);
+/// assert_eq!(err, Error("failed to open config.toml: 2".to_string()));
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[macro_export]
+macro_rules! err_fmt {
+    ($($arg:tt)*) => {
+        $crate::__private::Into::into($crate::__private::format!($($arg)*))
+    };
+}
+
+/// Returns early with a formatted error converted using [`Into::into`]
+///
+/// Shorthand for `return Err(format!(...).into())`:
+///
+/// ```rust
+/// use err_into::bail_fmt;
+///
+/// fn check(val: i32) -> Result<i32, Box<dyn std::error::Error>> {
+///     if val < 0 {
+///         bail_fmt!("{} is negative", val);
+///     }
+///     Ok(val)
+/// }
+///
+/// assert_eq!(check(-1).unwrap_err().to_string(), "-1 is negative");
+/// assert_eq!(check(1).unwrap(), 1);
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[macro_export]
+macro_rules! bail_fmt {
+    ($($arg:tt)*) => {
+        return $crate::__private::Err($crate::err_fmt!($($arg)*))
+    };
+}
+
+/// Runs a block where `?` converts errors using [`Into::into`], like a `try` block on stable
+///
+/// The block is evaluated inside an immediately invoked closure and its value is wrapped in
+/// [`Ok`], so every `?` converts its error to the one of the resulting [`Result`]:
+///
+/// ```rust
+/// use err_into::try_into_block;
+///
+/// fn step1() -> Result<(), u8> {
+///     Ok(())
+/// }
+///
+/// fn step2(val: i32) -> Result<i32, i16> {
+///     if val < 0 { Err(-1) } else { Ok(val * 2) }
+/// }
+///
+/// let ok: Result<i32, i32> = try_into_block! {
+///     step1()?;
+///     step2(2)?
+/// };
+/// assert_eq!(ok, Ok(4));
+///
+/// let err: Result<i32, i32> = try_into_block! {
+///     step1()?;
+///     step2(-2)?
+/// };
+/// assert_eq!(err, Err(-1));
+/// ```
+///
+/// Because of the closure, `return` exits the block instead of the enclosing function, and
+/// `break`/`continue` can't reach loops outside of it.
+#[macro_export]
+macro_rules! try_into_block {
+    ($($body:tt)*) => {
+        (|| $crate::__private::Ok({ $($body)* }))()
+    };
+}
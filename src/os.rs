@@ -0,0 +1,149 @@
+//! Conversions from the error codes returned by C APIs
+
+use core::convert::TryInto;
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+/// Types that can be built from a raw OS error code (an `errno` value)
+///
+/// Implement it for your error type to convert C error codes with [`ErrFromErrno`]:
+///
+/// ```rust
+/// use err_into::{ErrFromErrno, FromRawOsError};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum DeviceError {
+///     Busy,
+///     Other(i32),
+/// }
+///
+/// impl FromRawOsError for DeviceError {
+///     fn from_raw_os_error(code: i32) -> Self {
+///         match code {
+///             16 => DeviceError::Busy,
+///             code => DeviceError::Other(code),
+///         }
+///     }
+/// }
+///
+/// assert_eq!((-16i32).err_from_errno(), Err::<i32, _>(DeviceError::Busy));
+/// ```
+pub trait FromRawOsError {
+    fn from_raw_os_error(code: i32) -> Self;
+}
+
+/// A raw OS error code
+///
+/// The default target for [`ErrFromErrno`] when no specific error type is needed. Implement
+/// `From<Errno>` for your error type to keep converting it with [`ErrorInto`](crate::ErrorInto).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Errno(pub i32);
+
+impl fmt::Display for Errno {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "os error {}", self.0)
+    }
+}
+
+impl Error for Errno {}
+
+impl FromRawOsError for Errno {
+    #[inline]
+    fn from_raw_os_error(code: i32) -> Self {
+        Errno(code)
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromRawOsError for io::Error {
+    #[inline]
+    fn from_raw_os_error(code: i32) -> Self {
+        io::Error::from_raw_os_error(code)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Errno> for io::Error {
+    #[inline]
+    fn from(errno: Errno) -> Self {
+        io::Error::from_raw_os_error(errno.0)
+    }
+}
+
+/// Checks the return code of a C function, converting failures with [`FromRawOsError`]
+///
+/// Implemented for the integer types C functions usually return.
+///
+/// ```rust
+/// use err_into::{Errno, ErrFromErrno, ErrorInto};
+///
+/// // Negative return codes hold the error, like in Linux system calls
+/// assert_eq!(3i32.err_from_errno::<Errno>(), Ok(3));
+/// assert_eq!((-22i32).err_from_errno::<Errno>(), Err(Errno(22)));
+///
+/// // Or the error is stored in `errno` after returning `-1`
+/// let errno = || 11;
+/// assert_eq!((-1isize).err_from_errno_with::<Errno, _>(errno), Err(Errno(11)));
+///
+/// // `Errno` keeps converting with `ErrorInto`
+/// #[derive(Debug, PartialEq)]
+/// struct MyError(i32);
+///
+/// impl From<Errno> for MyError {
+///     fn from(errno: Errno) -> Self {
+///         MyError(errno.0)
+///     }
+/// }
+///
+/// let res: Result<i32, MyError> = (-5i32).err_from_errno::<Errno>().err_into();
+/// assert_eq!(res, Err(MyError(5)));
+/// ```
+pub trait ErrFromErrno: Sized {
+    /// Treats negative values as the negated error code
+    fn err_from_errno<E>(self) -> Result<Self, E>
+    where
+        E: FromRawOsError;
+
+    /// Treats negative values as failures, calling `errno` to get the error code
+    fn err_from_errno_with<E, F>(self, errno: F) -> Result<Self, E>
+    where
+        E: FromRawOsError,
+        F: FnOnce() -> i32;
+}
+
+macro_rules! impl_err_from_errno {
+    ($($int:ty),+) => {$(
+        impl ErrFromErrno for $int {
+            #[inline]
+            fn err_from_errno<E>(self) -> Result<Self, E>
+            where
+                E: FromRawOsError,
+            {
+                if self < 0 {
+                    // Saturate codes that don't fit an `i32`, they are not valid `errno` values
+                    let code = self.checked_neg().and_then(|code| code.try_into().ok());
+                    Err(E::from_raw_os_error(code.unwrap_or(i32::MAX)))
+                } else {
+                    Ok(self)
+                }
+            }
+
+            #[inline]
+            fn err_from_errno_with<E, F>(self, errno: F) -> Result<Self, E>
+            where
+                E: FromRawOsError,
+                F: FnOnce() -> i32,
+            {
+                if self < 0 {
+                    Err(E::from_raw_os_error(errno()))
+                } else {
+                    Ok(self)
+                }
+            }
+        }
+    )+};
+}
+
+impl_err_from_errno!(i8, i16, i32, i64, isize);
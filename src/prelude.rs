@@ -0,0 +1,28 @@
+//! Re-exports every extension trait
+//!
+//! ```rust
+//! use err_into::prelude::*;
+//!
+//! let _: Option<i32> = Some(0u8).map_into();
+//! let _: Result<(), i32> = Err(0u8).err_into();
+//! ```
+
+pub use {
+    AllInto, AsDerefInto, AsRefInto, BimapInto, BreakInto, ContextInto, ContinueInto, Conv,
+    ErrLocated, ErrOpt, ErrorInto, ErrorIntoAs, ErrorIntoVia, ErrorTryInto, FlattenInto, FlowInto,
+    FutureInto, IntoControlFlowInto, IntoErrInto, IntoExt, IntoOkInto, IntoResultInto, IntoVia,
+    IteratorInto, MapInto, MapIntoAs, MapTryInto, OkInto, OkOrDefaultErr, OkOrInto, ResultInto,
+    ResultIntoVia, ResultTryInto, Sources, TransposeInto, TryConv, WithCodeInto, ZipErrsInto,
+    ZipInto,
+};
+
+#[cfg(feature = "nightly")]
+pub use AsyncIteratorInto;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use ErrArc;
+#[cfg(feature = "alloc")]
+pub use {ErrBoxed, ErrDebug, ErrIntoOwned, ErrRc, ErrToString, KeysInto, ValuesInto, VecInto};
+#[cfg(feature = "windows")]
+pub use {ErrFromHresult, ErrFromWin32};
+#[cfg(feature = "std")]
+pub use {ErrIntoIo, ErrTraced, IntoExitCode};
@@ -0,0 +1,128 @@
+//! Helpers for returning errors from `main`
+
+use core::error::Error;
+use std::eprintln;
+use std::process::{ExitCode, Termination};
+
+use Sources;
+
+/// The result of `main`, printing its error with [`Display`](core::fmt::Display)
+///
+/// Returning a [`Result`] from `main` prints the error with [`Debug`], which is rarely meant for
+/// users. `MainResult` prints the error and its [`source`](Error::source) chain instead, and can be
+/// built from any `Result<(), F>` where `F` converts into the error:
+///
+/// ```rust,no_run
+/// use std::fmt;
+/// use err_into::MainResult;
+///
+/// #[derive(Debug)]
+/// struct AppError(u8);
+///
+/// impl fmt::Display for AppError {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         write!(f, "failed with {}", self.0)
+///     }
+/// }
+///
+/// impl std::error::Error for AppError {}
+///
+/// impl From<u8> for AppError {
+///     fn from(code: u8) -> Self {
+///         AppError(code)
+///     }
+/// }
+///
+/// fn run() -> Result<(), u8> {
+///     Err(2)
+/// }
+///
+/// // Prints `Error: failed with 2`
+/// fn main() -> MainResult<AppError> {
+///     run().into()
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MainResult<E>(pub Result<(), E>);
+
+impl<E, F> From<Result<(), F>> for MainResult<E>
+where
+    F: Into<E>,
+{
+    #[inline]
+    fn from(res: Result<(), F>) -> Self {
+        MainResult(res.map_err(Into::into))
+    }
+}
+
+impl<E> Termination for MainResult<E>
+where
+    E: Error,
+{
+    fn report(self) -> ExitCode {
+        let err = match self.0 {
+            Ok(()) => return ExitCode::SUCCESS,
+            Err(err) => err,
+        };
+        eprintln!("Error: {}", err);
+        let mut sources = err.sources().peekable();
+        if sources.peek().is_some() {
+            eprintln!("\nCaused by:");
+        }
+        for err in sources {
+            eprintln!("    {}", err);
+        }
+        ExitCode::FAILURE
+    }
+}
+
+/// Converts a value into an [`ExitCode`] using [`Into::into`]
+///
+/// Implemented for everything converting into an [`ExitCode`], so implement
+/// `From<YourError> for ExitCode` to map your errors to exit codes:
+///
+/// ```rust
+/// use std::process::ExitCode;
+/// use err_into::IntoExitCode;
+///
+/// enum CliError {
+///     Usage,
+///     Io,
+/// }
+///
+/// impl From<CliError> for ExitCode {
+///     fn from(err: CliError) -> Self {
+///         match err {
+///             CliError::Usage => ExitCode::from(64),
+///             CliError::Io => ExitCode::from(74),
+///         }
+///     }
+/// }
+///
+/// fn run() -> Result<(), CliError> {
+///     Err(CliError::Usage)
+/// }
+///
+/// // Usually `main`
+/// fn cli() -> ExitCode {
+///     if let Err(err) = run() {
+///         return err.exit_code_into();
+///     }
+///     ExitCode::SUCCESS
+/// }
+///
+/// assert_eq!(cli(), ExitCode::from(64));
+/// ```
+pub trait IntoExitCode {
+    fn exit_code_into(self) -> ExitCode;
+}
+
+impl<T> IntoExitCode for T
+where
+    T: Into<ExitCode>,
+{
+    #[inline]
+    fn exit_code_into(self) -> ExitCode {
+        self.into()
+    }
+}
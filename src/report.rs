@@ -0,0 +1,149 @@
+//! A lightweight error report
+
+use core::error::Error;
+use core::fmt;
+use core::panic::Location;
+
+use Sources;
+
+/// An error together with an optional message and the location where it was reported
+///
+/// A small `no_std`, allocation-free take on `anyhow::Error` that keeps the concrete error type:
+/// any error converting into `E` converts into a `Report<E>`, so `?` works like it would with
+/// `E`, and records where it happened.
+///
+/// The [`Display`](fmt::Display) output is the message followed by the whole source chain, and the
+/// [`Debug`] output lists the chain and the location, which makes it a good return type for
+/// `main`:
+///
+/// ```rust
+/// use std::num::ParseIntError;
+/// use err_into::Report;
+///
+/// fn parse(s: &str) -> Result<u8, Report<ParseIntError>> {
+///     let val = s.parse()?;
+///     Ok(val)
+/// }
+///
+/// let report = parse("a").unwrap_err().context("reading the device id");
+/// assert_eq!(report.to_string(), "reading the device id: invalid digit found in string");
+/// // Points to the `?` in `parse`
+/// assert_eq!(report.location().file(), file!());
+/// ```
+///
+/// Like `anyhow::Error`, it does not implement [`Error`] itself: that would conflict with the
+/// blanket [`From`] impl. Use [`Report::error`] to get to the underlying error.
+pub struct Report<E> {
+    error: E,
+    msg: Option<&'static str>,
+    location: &'static Location<'static>,
+}
+
+impl<E> Report<E> {
+    /// Wraps `error`, capturing the location of the caller
+    #[inline]
+    #[track_caller]
+    pub fn new(error: E) -> Self {
+        Report {
+            error,
+            msg: None,
+            location: Location::caller(),
+        }
+    }
+
+    /// Attaches a message describing what was being done when the error happened
+    ///
+    /// Replaces the previous message, if any.
+    #[inline]
+    pub fn context(self, msg: &'static str) -> Self {
+        Report {
+            msg: Some(msg),
+            ..self
+        }
+    }
+
+    /// The reported error
+    #[inline]
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// The message attached to the error, if any
+    #[inline]
+    pub fn msg(&self) -> Option<&'static str> {
+        self.msg
+    }
+
+    /// Where the error was reported
+    #[inline]
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Unwraps the error, discarding the message and location
+    #[inline]
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E, F> From<F> for Report<E>
+where
+    F: Error + Into<E>,
+{
+    #[inline]
+    #[track_caller]
+    fn from(err: F) -> Self {
+        Report::new(err.into())
+    }
+}
+
+impl<E> fmt::Display for Report<E>
+where
+    E: Error,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(msg) = self.msg {
+            write!(f, "{}: ", msg)?;
+        }
+        write!(f, "{}", self.error)?;
+        for err in self.error.sources() {
+            write!(f, ": {}", err)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E> fmt::Debug for Report<E>
+where
+    E: Error,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            return f
+                .debug_struct("Report")
+                .field("error", &self.error)
+                .field("msg", &self.msg)
+                .field("location", &self.location)
+                .finish();
+        }
+        let mut causes: Option<&dyn Error> = match self.msg {
+            Some(msg) => {
+                writeln!(f, "{}", msg)?;
+                Some(&self.error)
+            }
+            None => {
+                writeln!(f, "{}", self.error)?;
+                self.error.source()
+            }
+        };
+        if causes.is_some() {
+            writeln!(f, "\nCaused by:")?;
+        }
+        while let Some(err) = causes {
+            writeln!(f, "    {}", err)?;
+            causes = err.source();
+        }
+        write!(f, "\nLocation: {}", self.location)
+    }
+}
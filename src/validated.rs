@@ -0,0 +1,524 @@
+//! A [`Result`]-like type accumulating every error
+
+use core::fmt;
+use core::iter::{FromIterator, FusedIterator};
+use core::mem::{self, ManuallyDrop, MaybeUninit};
+use core::ptr;
+use core::slice;
+
+use {ErrorInto, Map};
+
+/// Either a valid value or every error found while building it
+///
+/// Unlike [`Result`], combining two `Validated` keeps the errors of both, so validating a form or
+/// a config file reports all of its problems at once. The errors are stored in a collection `C`
+/// chosen by the caller: a `Vec` when allocating is fine, an [`ErrorArray`] when it is not, or any
+/// other collection implementing [`Default`] and [`Extend`].
+///
+/// ```rust
+/// use err_into::Validated;
+///
+/// fn port(val: i32) -> Validated<u16, Vec<String>> {
+///     if (1..=65535).contains(&val) {
+///         Validated::Valid(val as u16)
+///     } else {
+///         Validated::invalid(format!("invalid port {}", val))
+///     }
+/// }
+///
+/// fn user(val: &str) -> Validated<String, Vec<String>> {
+///     if val.is_empty() {
+///         Validated::invalid("empty user".to_string())
+///     } else {
+///         Validated::Valid(val.to_string())
+///     }
+/// }
+///
+/// assert_eq!(port(22).and(user("root")).into_result(), Ok((22, "root".to_string())));
+/// assert_eq!(
+///     port(0).and(user("")).into_result(),
+///     Err(vec!["invalid port 0".to_string(), "empty user".to_string()]),
+/// );
+/// ```
+///
+/// It can also be collected from an iterator of [`Result`]s, keeping every error:
+///
+/// ```rust
+/// use err_into::Validated;
+///
+/// let all: Validated<Vec<u8>, Vec<_>> =
+///     ["1", "a", "3", "b"].iter().map(|s| s.parse::<u8>()).collect();
+/// assert_eq!(all.into_result().unwrap_err().len(), 2);
+/// ```
+///
+/// And converted like a [`Result`], with [`MapInto`](crate::MapInto) for the value and
+/// [`ErrorInto`] for each of the errors:
+///
+/// ```rust
+/// use err_into::{ErrorInto, MapInto, Validated};
+///
+/// let valid: Validated<u8, Vec<u8>> = Validated::Valid(1);
+/// assert_eq!(valid.map_into(), Validated::<u32, Vec<u8>>::Valid(1));
+///
+/// let invalid: Validated<(), Vec<u8>> = Validated::Invalid(vec![1, 2]);
+/// assert_eq!(invalid.err_into(), Validated::<(), Vec<u32>>::Invalid(vec![1, 2]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Validated<T, C> {
+    /// A valid value
+    Valid(T),
+    /// The errors found, never empty when built through this API
+    Invalid(C),
+}
+
+impl<T, C> Validated<T, C> {
+    /// Builds an invalid value from a single error
+    #[inline]
+    pub fn invalid<E>(err: E) -> Self
+    where
+        C: Default + Extend<E>,
+    {
+        let mut errs = C::default();
+        errs.extend(Some(err));
+        Validated::Invalid(errs)
+    }
+
+    /// Returns `true` if the value is [`Valid`](Validated::Valid)
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            Validated::Valid(_) => true,
+            Validated::Invalid(_) => false,
+        }
+    }
+
+    /// Converts into a [`Result`] holding the value or the collection of errors
+    #[inline]
+    pub fn into_result(self) -> Result<T, C> {
+        match self {
+            Validated::Valid(value) => Ok(value),
+            Validated::Invalid(errs) => Err(errs),
+        }
+    }
+
+    /// Pairs two values, accumulating the errors of both if either is invalid
+    #[inline]
+    pub fn and<U>(self, other: Validated<U, C>) -> Validated<(T, U), C>
+    where
+        C: IntoIterator + Extend<<C as IntoIterator>::Item>,
+    {
+        match (self, other) {
+            (Validated::Valid(a), Validated::Valid(b)) => Validated::Valid((a, b)),
+            (Validated::Invalid(errs), Validated::Valid(_))
+            | (Validated::Valid(_), Validated::Invalid(errs)) => Validated::Invalid(errs),
+            (Validated::Invalid(mut errs), Validated::Invalid(more)) => {
+                errs.extend(more);
+                Validated::Invalid(errs)
+            }
+        }
+    }
+
+    /// Converts a [`Result`], converting its error using [`Into::into`]
+    #[inline]
+    pub fn from_result_into<E, F>(res: Result<T, F>) -> Self
+    where
+        F: Into<E>,
+        C: Default + Extend<E>,
+    {
+        match res {
+            Ok(value) => Validated::Valid(value),
+            Err(err) => Validated::invalid(err.into()),
+        }
+    }
+}
+
+impl<T, C> From<Result<T, C>> for Validated<T, C> {
+    #[inline]
+    fn from(res: Result<T, C>) -> Self {
+        match res {
+            Ok(value) => Validated::Valid(value),
+            Err(errs) => Validated::Invalid(errs),
+        }
+    }
+}
+
+impl<T, C> From<Validated<T, C>> for Result<T, C> {
+    #[inline]
+    fn from(validated: Validated<T, C>) -> Self {
+        validated.into_result()
+    }
+}
+
+impl<T, E, V, C> FromIterator<Result<T, E>> for Validated<V, C>
+where
+    V: Default + Extend<T>,
+    C: Default + Extend<E>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        let mut values = V::default();
+        let mut errs: Option<C> = None;
+        for res in iter {
+            match res {
+                Ok(value) if errs.is_none() => values.extend(Some(value)),
+                Ok(_) => {}
+                Err(err) => errs.get_or_insert_with(C::default).extend(Some(err)),
+            }
+        }
+        match errs {
+            Some(errs) => Validated::Invalid(errs),
+            None => Validated::Valid(values),
+        }
+    }
+}
+
+impl<T, C> Map for Validated<T, C> {
+    type Item = T;
+    type Mapped<U> = Validated<U, C>;
+
+    #[inline]
+    fn map_raw<U, F>(self, mut f: F) -> Validated<U, C>
+    where
+        F: FnMut(T) -> U,
+    {
+        match self {
+            Validated::Valid(value) => Validated::Valid(f(value)),
+            Validated::Invalid(errs) => Validated::Invalid(errs),
+        }
+    }
+}
+
+#[diagnostic::do_not_recommend]
+impl<T, E, C, D> ErrorInto<T, E, Validated<T, D>> for Validated<T, C>
+where
+    C: IntoIterator,
+    C::Item: Into<E>,
+    D: FromIterator<E>,
+{
+    #[inline]
+    fn err_into(self) -> Validated<T, D> {
+        match self {
+            Validated::Valid(value) => Validated::Valid(value),
+            Validated::Invalid(errs) => {
+                Validated::Invalid(errs.into_iter().map(Into::into).collect())
+            }
+        }
+    }
+}
+
+/// A fixed-capacity collection of errors for [`Validated`], usable without an allocator
+///
+/// Stores up to `N` errors inline. Errors added once it is full are dropped, and only counted by
+/// [`ErrorArray::dropped`]:
+///
+/// ```rust
+/// use err_into::{ErrorArray, Validated};
+///
+/// fn port(val: i32) -> Validated<u16, ErrorArray<&'static str, 2>> {
+///     if (1..=65535).contains(&val) {
+///         Validated::Valid(val as u16)
+///     } else {
+///         Validated::invalid("invalid port")
+///     }
+/// }
+///
+/// fn user(val: &str) -> Validated<&str, ErrorArray<&'static str, 2>> {
+///     if val.is_empty() {
+///         Validated::invalid("empty user")
+///     } else {
+///         Validated::Valid(val)
+///     }
+/// }
+///
+/// let errs = port(0).and(user("")).into_result().unwrap_err();
+/// assert_eq!(errs.as_slice(), ["invalid port", "empty user"]);
+///
+/// let errs = port(0).and(user("")).and(port(-1)).into_result().unwrap_err();
+/// assert_eq!(errs.len(), 2);
+/// assert_eq!(errs.dropped(), 1);
+/// ```
+///
+/// Every error is dropped exactly once, whether it overflows, is cloned or is left in the array:
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use err_into::ErrorArray;
+///
+/// let err = Rc::new(());
+/// let errs: ErrorArray<Rc<()>, 2> = (0..3).map(|_| Rc::clone(&err)).collect();
+/// assert_eq!((errs.len(), errs.dropped()), (2, 1));
+/// assert_eq!(Rc::strong_count(&err), 3);
+///
+/// let copy = errs.clone();
+/// assert_eq!(copy, errs);
+/// assert_eq!(Rc::strong_count(&err), 5);
+///
+/// drop(copy);
+/// drop(errs);
+/// assert_eq!(Rc::strong_count(&err), 1);
+/// ```
+///
+/// It needs room for at least one error, so [`Validated::invalid`] is never empty:
+///
+/// ```rust,compile_fail
+/// use err_into::ErrorArray;
+///
+/// let errs = ErrorArray::<&str, 0>::new();
+/// ```
+pub struct ErrorArray<E, const N: usize> {
+    errs: [MaybeUninit<E>; N],
+    len: usize,
+    dropped: usize,
+}
+
+impl<E, const N: usize> ErrorArray<E, N> {
+    /// An empty collection, fails to compile if `N` is 0
+    #[inline]
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "an `ErrorArray` needs room for at least one error") };
+        ErrorArray {
+            errs: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Adds an error, handing it back if the collection is full
+    #[inline]
+    pub fn push(&mut self, err: E) -> Result<(), E> {
+        match self.errs.get_mut(self.len) {
+            Some(slot) => {
+                slot.write(err);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(err),
+        }
+    }
+
+    /// The stored errors
+    #[inline]
+    pub fn as_slice(&self) -> &[E] {
+        // SAFETY: the first `len` errors are initialized
+        unsafe { slice::from_raw_parts(self.errs.as_ptr().cast(), self.len) }
+    }
+
+    /// The number of stored errors
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no error is stored
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of errors dropped because the collection was full
+    #[inline]
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+}
+
+impl<E, const N: usize> Drop for ErrorArray<E, N> {
+    fn drop(&mut self) {
+        // SAFETY: the first `len` errors are initialized, and never used again
+        unsafe {
+            let errs = self.errs.as_mut_ptr().cast::<E>();
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(errs, self.len));
+        }
+    }
+}
+
+impl<E, const N: usize> Default for ErrorArray<E, N> {
+    #[inline]
+    fn default() -> Self {
+        ErrorArray::new()
+    }
+}
+
+impl<E, const N: usize> Clone for ErrorArray<E, N>
+where
+    E: Clone,
+{
+    fn clone(&self) -> Self {
+        let mut errs = ErrorArray::new();
+        errs.extend(self.as_slice().iter().cloned());
+        errs.dropped = self.dropped;
+        errs
+    }
+}
+
+impl<E, const N: usize> fmt::Debug for ErrorArray<E, N>
+where
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<E, const N: usize> PartialEq for ErrorArray<E, N>
+where
+    E: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice() && self.dropped == other.dropped
+    }
+}
+
+impl<E, const N: usize> Eq for ErrorArray<E, N> where E: Eq {}
+
+impl<E, const N: usize> Extend<E> for ErrorArray<E, N> {
+    #[inline]
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for err in iter {
+            if self.push(err).is_err() {
+                self.dropped += 1;
+            }
+        }
+    }
+}
+
+impl<E, const N: usize> FromIterator<E> for ErrorArray<E, N> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+    {
+        let mut errs = ErrorArray::new();
+        errs.extend(iter);
+        errs
+    }
+}
+
+impl<'a, E, const N: usize> IntoIterator for &'a ErrorArray<E, N> {
+    type Item = &'a E;
+    type IntoIter = slice::Iter<'a, E>;
+
+    #[inline]
+    fn into_iter(self) -> slice::Iter<'a, E> {
+        self.as_slice().iter()
+    }
+}
+
+impl<E, const N: usize> IntoIterator for ErrorArray<E, N> {
+    type Item = E;
+    type IntoIter = ErrorArrayIntoIter<E, N>;
+
+    #[inline]
+    fn into_iter(self) -> ErrorArrayIntoIter<E, N> {
+        let end = self.len;
+        // The errors are moved into the iterator, don't drop them here
+        let mut this = ManuallyDrop::new(self);
+        let errs = mem::replace(&mut this.errs, [const { MaybeUninit::uninit() }; N]);
+        ErrorArrayIntoIter {
+            errs,
+            start: 0,
+            end,
+        }
+    }
+}
+
+/// An iterator moving the errors out of an [`ErrorArray`]
+///
+/// The errors it doesn't yield are dropped with it:
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use err_into::ErrorArray;
+///
+/// let err = Rc::new(());
+/// let errs: ErrorArray<Rc<()>, 4> = (0..4).map(|_| Rc::clone(&err)).collect();
+/// assert_eq!(Rc::strong_count(&err), 5);
+///
+/// let mut iter = errs.into_iter();
+/// drop(iter.next());
+/// drop(iter.next_back());
+/// assert_eq!(iter.len(), 2);
+/// assert_eq!(Rc::strong_count(&err), 3);
+///
+/// drop(iter);
+/// assert_eq!(Rc::strong_count(&err), 1);
+/// ```
+pub struct ErrorArrayIntoIter<E, const N: usize> {
+    errs: [MaybeUninit<E>; N],
+    start: usize,
+    end: usize,
+}
+
+impl<E, const N: usize> ErrorArrayIntoIter<E, N> {
+    /// The errors not yet yielded
+    #[inline]
+    pub fn as_slice(&self) -> &[E] {
+        // SAFETY: the errors in `start..end` are initialized
+        unsafe {
+            let errs = self.errs.as_ptr().add(self.start).cast();
+            slice::from_raw_parts(errs, self.end - self.start)
+        }
+    }
+}
+
+impl<E, const N: usize> Iterator for ErrorArrayIntoIter<E, N> {
+    type Item = E;
+
+    #[inline]
+    fn next(&mut self) -> Option<E> {
+        if self.start == self.end {
+            return None;
+        }
+        self.start += 1;
+        // SAFETY: the error was initialized, and is no longer part of `start..end`
+        Some(unsafe { self.errs[self.start - 1].assume_init_read() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<E, const N: usize> DoubleEndedIterator for ErrorArrayIntoIter<E, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<E> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        // SAFETY: the error was initialized, and is no longer part of `start..end`
+        Some(unsafe { self.errs[self.end].assume_init_read() })
+    }
+}
+
+impl<E, const N: usize> ExactSizeIterator for ErrorArrayIntoIter<E, N> {}
+
+impl<E, const N: usize> FusedIterator for ErrorArrayIntoIter<E, N> {}
+
+impl<E, const N: usize> Drop for ErrorArrayIntoIter<E, N> {
+    fn drop(&mut self) {
+        // SAFETY: the errors in `start..end` are initialized, and never used again
+        unsafe {
+            let errs = self.errs.as_mut_ptr().add(self.start).cast::<E>();
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(errs, self.end - self.start));
+        }
+    }
+}
+
+impl<E, const N: usize> fmt::Debug for ErrorArrayIntoIter<E, N>
+where
+    E: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("ErrorArrayIntoIter")
+            .field(&self.as_slice())
+            .finish()
+    }
+}
@@ -0,0 +1,252 @@
+//! Conversions from the error codes returned by Windows APIs
+//!
+//! The codes are plain integers so this doesn't depend on any bindings crate: `HRESULT` is an
+//! `i32` and `WIN32_ERROR` (the value returned by `GetLastError`) is a `u32`.
+
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+/// `FACILITY_WIN32`, the facility of `HRESULT`s wrapping a Win32 error code
+const FACILITY_WIN32: u32 = 7;
+
+/// Types that can be built from a failed `HRESULT`
+pub trait FromHresult {
+    fn from_hresult(hr: i32) -> Self;
+}
+
+/// Types that can be built from a Win32 error code
+pub trait FromWin32Error {
+    fn from_win32_error(code: u32) -> Self;
+}
+
+/// A failed `HRESULT`
+///
+/// [`Win32Error`]s convert into it like `HRESULT_FROM_WIN32` does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hresult(pub i32);
+
+/// A Win32 error code, as returned by `GetLastError`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Win32Error(pub u32);
+
+#[cfg(feature = "std")]
+impl Win32Error {
+    /// The [`io::ErrorKind`] of this error code
+    ///
+    /// Approximates the kind [`std`] gives the common codes on Windows, like missing files, denied
+    /// access or timeouts, and returns [`ErrorKind::Other`](io::ErrorKind::Other) for the rest.
+    /// Converting a `Win32Error` into an [`io::Error`] uses it on other targets, on Windows the
+    /// code becomes a raw OS error and gets the kind [`std`] gives it:
+    ///
+    /// ```rust
+    /// use std::io::{self, ErrorKind};
+    /// use err_into::{Hresult, Win32Error};
+    ///
+    /// const ERROR_FILE_NOT_FOUND: u32 = 2;
+    /// const ERROR_ACCESS_DENIED: u32 = 5;
+    ///
+    /// assert_eq!(io::Error::from(Win32Error(ERROR_FILE_NOT_FOUND)).kind(), ErrorKind::NotFound);
+    /// assert_eq!(
+    ///     io::Error::from(Hresult::from(Win32Error(ERROR_ACCESS_DENIED))).kind(),
+    ///     ErrorKind::PermissionDenied,
+    /// );
+    /// ```
+    #[inline]
+    pub fn kind(self) -> io::ErrorKind {
+        use std::io::ErrorKind;
+
+        match self.0 {
+            2 | 3 => ErrorKind::NotFound,
+            5 | 10013 => ErrorKind::PermissionDenied,
+            8 | 14 => ErrorKind::OutOfMemory,
+            50 => ErrorKind::Unsupported,
+            80 | 183 => ErrorKind::AlreadyExists,
+            87 | 10022 => ErrorKind::InvalidInput,
+            109 | 232 => ErrorKind::BrokenPipe,
+            121 | 258 | 1460 | 10060 => ErrorKind::TimedOut,
+            10035 => ErrorKind::WouldBlock,
+            10048 => ErrorKind::AddrInUse,
+            10049 => ErrorKind::AddrNotAvailable,
+            10053 => ErrorKind::ConnectionAborted,
+            10054 => ErrorKind::ConnectionReset,
+            10057 => ErrorKind::NotConnected,
+            10061 => ErrorKind::ConnectionRefused,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl Hresult {
+    /// The Win32 error code wrapped by this `HRESULT`, if its facility is `FACILITY_WIN32`
+    #[inline]
+    pub fn win32_error(self) -> Option<Win32Error> {
+        let hr = self.0 as u32;
+        if (hr >> 16) & 0x1fff == FACILITY_WIN32 {
+            Some(Win32Error(hr & 0xffff))
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for Hresult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "HRESULT {:#010x}", self.0)
+    }
+}
+
+impl fmt::Display for Win32Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "win32 error {}", self.0)
+    }
+}
+
+impl Error for Hresult {}
+
+impl Error for Win32Error {}
+
+impl From<Win32Error> for Hresult {
+    #[inline]
+    fn from(err: Win32Error) -> Self {
+        // HRESULT_FROM_WIN32
+        if err.0 as i32 <= 0 {
+            Hresult(err.0 as i32)
+        } else {
+            Hresult(((err.0 & 0xffff) | (FACILITY_WIN32 << 16) | 0x8000_0000) as i32)
+        }
+    }
+}
+
+impl FromHresult for Hresult {
+    #[inline]
+    fn from_hresult(hr: i32) -> Self {
+        Hresult(hr)
+    }
+}
+
+impl FromWin32Error for Win32Error {
+    #[inline]
+    fn from_win32_error(code: u32) -> Self {
+        Win32Error(code)
+    }
+}
+
+impl FromWin32Error for Hresult {
+    #[inline]
+    fn from_win32_error(code: u32) -> Self {
+        Win32Error(code).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromHresult for io::Error {
+    #[inline]
+    fn from_hresult(hr: i32) -> Self {
+        Hresult(hr).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromWin32Error for io::Error {
+    #[inline]
+    fn from_win32_error(code: u32) -> Self {
+        Win32Error(code).into()
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Hresult> for io::Error {
+    #[inline]
+    fn from(hr: Hresult) -> Self {
+        match hr.win32_error() {
+            Some(err) => err.into(),
+            None if cfg!(windows) => io::Error::from_raw_os_error(hr.0),
+            None => io::Error::other(hr),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Win32Error> for io::Error {
+    #[inline]
+    fn from(err: Win32Error) -> Self {
+        // Raw OS errors are only Win32 error codes on Windows, elsewhere they are `errno` values
+        if cfg!(windows) {
+            io::Error::from_raw_os_error(err.0 as i32)
+        } else {
+            io::Error::new(err.kind(), err)
+        }
+    }
+}
+
+/// Checks an `HRESULT`, converting failures (negative values) with [`FromHresult`]
+///
+/// Success codes like `S_FALSE` are kept in the [`Ok`] variant.
+///
+/// ```rust
+/// use err_into::{ErrFromHresult, Hresult, Win32Error};
+///
+/// const S_FALSE: i32 = 1;
+/// const E_ACCESSDENIED: i32 = 0x8007_0005_u32 as i32;
+///
+/// assert_eq!(S_FALSE.err_from_hresult::<Hresult>(), Ok(S_FALSE));
+///
+/// let err = E_ACCESSDENIED.err_from_hresult::<Hresult>().unwrap_err();
+/// assert_eq!(err.win32_error(), Some(Win32Error(5)));
+/// ```
+pub trait ErrFromHresult: Sized {
+    fn err_from_hresult<E>(self) -> Result<Self, E>
+    where
+        E: FromHresult;
+}
+
+/// Checks a Win32 error code, converting anything but `ERROR_SUCCESS` with [`FromWin32Error`]
+///
+/// ```rust
+/// use err_into::{ErrFromWin32, Hresult, Win32Error};
+///
+/// const ERROR_SUCCESS: u32 = 0;
+/// const ERROR_ACCESS_DENIED: u32 = 5;
+///
+/// assert_eq!(ERROR_SUCCESS.err_from_win32::<Win32Error>(), Ok(()));
+/// assert_eq!(ERROR_ACCESS_DENIED.err_from_win32::<Win32Error>(), Err(Win32Error(5)));
+/// assert_eq!(
+///     ERROR_ACCESS_DENIED.err_from_win32::<Hresult>(),
+///     Err(Hresult(0x8007_0005_u32 as i32))
+/// );
+/// ```
+pub trait ErrFromWin32 {
+    fn err_from_win32<E>(self) -> Result<(), E>
+    where
+        E: FromWin32Error;
+}
+
+impl ErrFromHresult for i32 {
+    #[inline]
+    fn err_from_hresult<E>(self) -> Result<i32, E>
+    where
+        E: FromHresult,
+    {
+        if self < 0 {
+            Err(E::from_hresult(self))
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+impl ErrFromWin32 for u32 {
+    #[inline]
+    fn err_from_win32<E>(self) -> Result<(), E>
+    where
+        E: FromWin32Error,
+    {
+        if self == 0 {
+            Ok(())
+        } else {
+            Err(E::from_win32_error(self))
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Checks that the conversions compile to the same machine code as the code they replace
+//!
+//! Every file in `tests/codegen` is built in release mode, and every `#[no_mangle]` function named
+//! `<name>_expected` must compile to the same assembly as the function `<name>`.
+
+mod common;
+
+use std::collections::HashMap;
+use std::fs;
+
+use common::Fixture;
+
+/// The functions of an assembly file, ignoring directives and label numbers
+fn functions(asm: &str) -> HashMap<String, Vec<String>> {
+    let mut functions = HashMap::new();
+    let mut aliases = Vec::new();
+    let mut current: Option<(String, Vec<String>)> = None;
+    for line in asm.lines() {
+        let line = line.trim();
+        if let Some((name, target)) = line.split_once(" = ") {
+            aliases.push((name.to_string(), target.to_string()));
+        } else if let Some(alias) = line.strip_prefix(".set ") {
+            let (name, target) = alias.split_once(", ").unwrap();
+            aliases.push((name.to_string(), target.to_string()));
+        } else if line.trim_start_matches('.').starts_with("Lfunc_end") {
+            if let Some((name, body)) = current.take() {
+                functions.insert(name, body);
+            }
+        } else if let Some((_, body)) = current.as_mut() {
+            if !line.starts_with('.') || line.trim_start_matches('.').starts_with("LBB") {
+                body.push(normalize(line));
+            }
+        } else if let Some(name) = line.strip_suffix(':') {
+            if !name.starts_with('.') {
+                current = Some((name.to_string(), Vec::new()));
+            }
+        }
+    }
+    for (name, target) in aliases {
+        if let Some(body) = functions.get(&target).cloned() {
+            functions.insert(name, body);
+        }
+    }
+    functions
+}
+
+/// Replaces the numbers of the basic block labels, they depend on the position of the function
+fn normalize(line: &str) -> String {
+    let mut normalized = String::new();
+    let mut rest = line;
+    while let Some(idx) = rest.find("LBB") {
+        normalized.push_str(&rest[..idx + 3]);
+        rest = rest[idx + 3..].trim_start_matches(|c: char| c.is_ascii_digit() || c == '_');
+    }
+    normalized.push_str(rest);
+    normalized
+}
+
+#[test]
+fn codegen() {
+    let fixture = Fixture::new("codegen");
+    for src in Fixture::sources("codegen") {
+        let name = src.file_stem().unwrap().to_str().unwrap();
+        let asm_path = fixture.target_dir().join(format!("{}.s", name));
+        let emit = format!("--emit=asm={}", asm_path.display());
+        let output = fixture.cargo(&[
+            "rustc",
+            "--quiet",
+            "--release",
+            "--bin",
+            name,
+            "--",
+            "-Ccodegen-units=1",
+            &emit,
+        ]);
+        assert!(
+            output.status.success(),
+            "{} failed to build:\n{}",
+            name,
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let asm = fs::read_to_string(&asm_path).unwrap();
+        let functions = functions(&asm);
+        let mut checked = 0;
+        let mut mismatches = Vec::new();
+        for (expected_name, expected) in &functions {
+            let Some(actual_name) = expected_name.strip_suffix("_expected") else {
+                continue;
+            };
+            let actual = &functions[actual_name];
+            if actual != expected {
+                mismatches.push(format!(
+                    "`{}`:\n    {}\n`{}`:\n    {}",
+                    actual_name,
+                    actual.join("\n    "),
+                    expected_name,
+                    expected.join("\n    ")
+                ));
+            }
+            checked += 1;
+        }
+        assert!(
+            mismatches.is_empty(),
+            "{}: functions compile differently:\n{}",
+            name,
+            mismatches.join("\n\n")
+        );
+        assert!(checked > 0, "{}: no `_expected` functions found", name);
+    }
+}
@@ -0,0 +1,56 @@
+//! `err_into`, `map_into` and `res_into` compile like the closures they replace, and `err_into`
+//! and `map_into` vanish when converting into the same type
+
+use err_into::{ErrorInto, MapInto, ResultInto};
+
+#[no_mangle]
+pub fn err_into(res: Result<u32, u16>) -> Result<u32, u64> {
+    res.err_into()
+}
+
+#[no_mangle]
+pub fn err_into_expected(res: Result<u32, u16>) -> Result<u32, u64> {
+    res.map_err(Into::into)
+}
+
+#[no_mangle]
+pub fn map_into(opt: Option<u16>) -> Option<u64> {
+    opt.map_into()
+}
+
+#[no_mangle]
+pub fn map_into_expected(opt: Option<u16>) -> Option<u64> {
+    opt.map(Into::into)
+}
+
+#[no_mangle]
+pub fn res_into(res: Result<u16, u8>) -> Result<u64, u32> {
+    res.res_into()
+}
+
+#[no_mangle]
+pub fn res_into_expected(res: Result<u16, u8>) -> Result<u64, u32> {
+    res.map(Into::into).map_err(Into::into)
+}
+
+#[no_mangle]
+pub fn err_into_identity(res: Result<u32, u16>) -> Result<u32, u16> {
+    res.err_into()
+}
+
+#[no_mangle]
+pub fn err_into_identity_expected(res: Result<u32, u16>) -> Result<u32, u16> {
+    res
+}
+
+#[no_mangle]
+pub fn map_into_identity(opt: Option<u16>) -> Option<u16> {
+    opt.map_into()
+}
+
+#[no_mangle]
+pub fn map_into_identity_expected(opt: Option<u16>) -> Option<u16> {
+    opt
+}
+
+fn main() {}
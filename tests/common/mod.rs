@@ -0,0 +1,68 @@
+//! Builds the fixtures of the integration tests as packages depending on `err-into`
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// A package in the target directory depending on `err-into`
+pub struct Fixture {
+    dir: PathBuf,
+}
+
+impl Fixture {
+    /// Creates the package `name` with a binary for every `.rs` file in `tests/<name>`
+    pub fn new(name: &str) -> Fixture {
+        let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+        let bin_dir = dir.join("src").join("bin");
+        let _ = fs::remove_dir_all(&bin_dir);
+        fs::create_dir_all(&bin_dir).unwrap();
+        fs::write(
+            dir.join("Cargo.toml"),
+            format!(
+                "[package]\nname = \"{}\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n\
+                 [dependencies]\nerr-into = {{ path = {:?} }}\n\n[workspace]\n",
+                name, manifest_dir,
+            ),
+        )
+        .unwrap();
+        for src in Fixture::sources(name) {
+            fs::copy(&src, bin_dir.join(src.file_name().unwrap())).unwrap();
+        }
+        Fixture { dir }
+    }
+
+    /// The `.rs` files in `tests/<name>`, sorted by name
+    pub fn sources(name: &str) -> Vec<PathBuf> {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join(name);
+        let mut sources: Vec<PathBuf> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension() == Some("rs".as_ref()))
+            .collect();
+        sources.sort();
+        sources
+    }
+
+    /// Runs `cargo <args>` in the package
+    pub fn cargo(&self, args: &[&str]) -> Output {
+        let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        Command::new(cargo)
+            .arg(args[0])
+            .arg("--manifest-path")
+            .arg(self.dir.join("Cargo.toml"))
+            .args(&args[1..])
+            .env("CARGO_TARGET_DIR", self.target_dir())
+            .env_remove("RUSTFLAGS")
+            .output()
+            .unwrap()
+    }
+
+    /// Where the package is built
+    pub fn target_dir(&self) -> PathBuf {
+        self.dir.join("target")
+    }
+}
@@ -0,0 +1,35 @@
+//! Checks the diagnostics of the conversion traits
+//!
+//! Every file in `tests/ui` must fail to build, and its `//~ ERROR <message>` comments must be found
+//! in the output of the compiler.
+
+mod common;
+
+use std::fs;
+
+use common::Fixture;
+
+#[test]
+fn ui() {
+    let fixture = Fixture::new("ui");
+    for src in Fixture::sources("ui") {
+        let name = src.file_stem().unwrap().to_str().unwrap();
+        let output = fixture.cargo(&["build", "--quiet", "--bin", name]);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(!output.status.success(), "{} built successfully", name);
+
+        let expected = fs::read_to_string(&src).unwrap();
+        for msg in expected
+            .lines()
+            .filter_map(|line| line.split("//~ ERROR ").nth(1))
+        {
+            assert!(
+                stderr.contains(msg),
+                "{}: `{}` not found in:\n{}",
+                name,
+                msg,
+                stderr
+            );
+        }
+    }
+}
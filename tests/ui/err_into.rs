@@ -0,0 +1,8 @@
+use err_into::ErrorInto;
+
+struct Foo;
+
+fn main() {
+    let _: Result<(), Foo> = Err::<(), u8>(1).err_into();
+    //~ ERROR cannot convert the error of `Result<(), u8>` into `Foo`
+}
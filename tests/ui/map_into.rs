@@ -0,0 +1,8 @@
+use err_into::MapInto;
+
+struct Foo;
+
+fn main() {
+    let _: Option<Foo> = Some(1u8).map_into();
+    //~ ERROR cannot map the values of `Option<u8>` into `Option<Foo>`
+}
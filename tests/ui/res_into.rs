@@ -0,0 +1,8 @@
+use err_into::ResultInto;
+
+struct Foo;
+
+fn main() {
+    let _: Result<Foo, i32> = Ok::<u8, u8>(1).res_into();
+    //~ ERROR cannot convert `Result<u8, u8>` into a result of `Foo` and `i32`
+}